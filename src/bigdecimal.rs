@@ -0,0 +1,474 @@
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    hash::{Hash, Hasher},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+    str::FromStr,
+};
+
+use crate::{detail, Context, Int, Rounding, Signal};
+
+// Return `10**n`.
+fn pow10(n: i64) -> Int {
+    Int::pow(&Int::from(10), &Int::from(n))
+}
+
+/// BigDecimal provides arbitrary precision decimal floating point arithmetic, like Python's `decimal.Decimal`.
+///
+/// Construction is exact, while arithmetic rounds the result according to the current [`Context`].
+/// There is no infinity or NaN, so division by zero and an impossible remainder always panic, and an untrapped overflow gives the largest finite magnitude.
+#[derive(Debug, Clone, Default)]
+pub struct BigDecimal {
+    // Signed coefficient, the value is `coef * 10**exp`.
+    coef: Int,
+
+    // Exponent.
+    exp: i64,
+
+    // Sign bit, which also distinguishes -0 from 0.
+    negative: bool,
+}
+
+impl BigDecimal {
+    // Round the coefficient to the precision and exponent range of the context.
+    fn finish(coef: Int, mut exp: i64, context: &Context) -> Self {
+        let prec = context.prec as i64;
+        let etiny = context.etiny();
+
+        if coef.is_zero() {
+            return Self {
+                coef,
+                exp: exp.clamp(etiny, context.emax),
+                negative: false,
+            };
+        }
+
+        let negative = coef.is_negative();
+        let digits = coef.digits() as i64;
+        let drop = (digits - prec).max(etiny - exp);
+
+        let mut coef = coef.abs();
+        if drop > 0 {
+            let (mut q, last, half, inexact) = if drop > digits + 1 {
+                // the whole coefficient is less than half a unit
+                (Int::new(), 0, Ordering::Less, true)
+            } else {
+                let unit = pow10(drop);
+                let (q, r) = coef.divmod(&unit);
+                let last = (&q % Int::from(10)).to_number::<i64>() as u8;
+                (q, last, (&r + &r).cmp(&unit), !r.is_zero())
+            };
+
+            Context::signal(Signal::Rounded, "Error: Rounded result.");
+            if inexact {
+                Context::signal(Signal::Inexact, "Error: Inexact result.");
+            }
+            if context.rounding.round_up(negative, last, half, inexact) {
+                q += &Int::from(1);
+            }
+            exp += drop;
+
+            // carry out of the precision, like 999.5 -> 1.00E+3
+            if q.digits() as i64 > prec {
+                q /= Int::from(10);
+                exp += 1;
+            }
+            coef = q;
+        }
+
+        if !coef.is_zero() && exp + coef.digits() as i64 - 1 > context.emax {
+            // there is no infinity, so the largest finite magnitude is the result if not trapped
+            Context::signal(Signal::Overflow, "Error: Decimal overflow.");
+            Context::signal_inexact();
+            coef = pow10(prec) - Int::from(1);
+            exp = context.emax - prec + 1;
+        }
+
+        Self {
+            coef: if negative { -coef } else { coef },
+            exp,
+            negative,
+        }
+    }
+
+    // Set the sign of a zero result, a nonzero result keeps its own sign.
+    fn with_zero_sign(mut self, negative: bool) -> Self {
+        if self.coef.is_zero() {
+            self.negative = negative;
+        }
+        self
+    }
+
+    // Return the value with the trailing zeros of the coefficient removed.
+    fn reduced(&self) -> (Int, i64) {
+        if self.coef.is_zero() {
+            return (Int::new(), 0);
+        }
+
+        let (ten, mut coef, mut exp) = (Int::from(10), self.coef.clone(), self.exp);
+        loop {
+            let (q, r) = coef.divmod(&ten);
+            if !r.is_zero() {
+                return (coef, exp);
+            }
+            coef = q;
+            exp += 1;
+        }
+    }
+
+    /// Construct a new zero decimal.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the signed coefficient, the value is `coefficient * 10**exponent`.
+    pub fn coefficient(&self) -> &Int {
+        &self.coef
+    }
+
+    /// Return the exponent, the value is `coefficient * 10**exponent`.
+    pub fn exponent(&self) -> i64 {
+        self.exp
+    }
+
+    /// Return the adjusted exponent, the exponent of the most significant digit, like Python's `Decimal.adjusted()`.
+    pub fn adjusted(&self) -> i64 {
+        self.exp + self.coef.digits().max(1) as i64 - 1
+    }
+
+    /// Return `true` if the value is zero.
+    pub fn is_zero(&self) -> bool {
+        self.coef.is_zero()
+    }
+
+    /// Return `true` if the value is negative, -0 is not negative.
+    pub fn is_negative(&self) -> bool {
+        self.coef.is_negative()
+    }
+
+    /// Return `true` if self has a negative sign, including -0.
+    pub fn is_signed(&self) -> bool {
+        self.negative
+    }
+
+    /// Return the absolute value, rounded to the current context.
+    pub fn abs(&self) -> Self {
+        Self::finish(self.coef.abs(), self.exp, &Context::current())
+    }
+
+    /// Return the value rounded to the current context, like Python's unary plus.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pyinrs::{BigDecimal, Context};
+    /// let x = BigDecimal::from("3.14159265358979");
+    /// assert_eq!(Context::local(Context { prec: 5, ..Context::default() }, || x.plus()).to_string(), "3.1416");
+    /// ```
+    pub fn plus(&self) -> Self {
+        let context = Context::current();
+        Self::finish(self.coef.clone(), self.exp, &context).with_zero_sign(self.negative && context.rounding == Rounding::Floor)
+    }
+}
+
+/*
+Construct
+*/
+
+impl From<&Int> for BigDecimal {
+    fn from(value: &Int) -> Self {
+        Self::from(value.clone())
+    }
+}
+
+impl From<Int> for BigDecimal {
+    fn from(value: Int) -> Self {
+        Self {
+            negative: value.is_negative(),
+            coef: value,
+            exp: 0,
+        }
+    }
+}
+
+macro_rules! from_integer {
+    ($($T:ty),*) => {$(
+        impl From<$T> for BigDecimal {
+            fn from(value: $T) -> Self {
+                Self::from(Int::from(value))
+            }
+        }
+    )*};
+}
+
+from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl From<&str> for BigDecimal {
+    fn from(s: &str) -> Self {
+        s.parse().unwrap_or_else(|_| panic!("Error: Wrong decimal literal."))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseBigDecimalError;
+
+impl FromStr for BigDecimal {
+    type Err = ParseBigDecimalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (mantissa, exp) = match s.find(['e', 'E']) {
+            Some(pos) => (&s[..pos], s[pos + 1..].parse::<i64>().map_err(|_| ParseBigDecimalError)?),
+            None => (s, 0),
+        };
+
+        let (sign, mantissa) = match mantissa.as_bytes().first() {
+            Some(b'-') => ("-", &mantissa[1..]),
+            Some(b'+') => ("", &mantissa[1..]),
+            _ => ("", mantissa),
+        };
+        let (integral, fractional) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        let is_digits = |part: &str| part.bytes().all(|c| c.is_ascii_digit());
+        if integral.len() + fractional.len() == 0 || !is_digits(integral) || !is_digits(fractional) {
+            return Err(ParseBigDecimalError);
+        }
+
+        let exp = exp.checked_sub(fractional.len() as i64).ok_or(ParseBigDecimalError)?;
+        Ok(Self {
+            coef: Int::from(format!("{sign}{integral}{fractional}").as_str()),
+            exp,
+            negative: sign == "-",
+        })
+    }
+}
+
+/*
+Function
+*/
+
+impl PartialEq for BigDecimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for BigDecimal {}
+
+impl Hash for BigDecimal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // equal values like 1.0 and 1.00 must hash the same
+        self.reduced().hash(state);
+    }
+}
+
+impl PartialOrd for BigDecimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigDecimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let sign = |x: &Self| {
+            if x.is_zero() {
+                0
+            } else if x.is_negative() {
+                -1
+            } else {
+                1
+            }
+        };
+        let (a, b) = (sign(self), sign(other));
+        if a != b || a == 0 {
+            return a.cmp(&b);
+        }
+
+        // same sign, compare the magnitudes
+        let ord = self.adjusted().cmp(&other.adjusted()).then_with(|| {
+            let exp = self.exp.min(other.exp);
+            let lhs = self.coef.abs() * pow10(self.exp - exp);
+            let rhs = other.coef.abs() * pow10(other.exp - exp);
+            lhs.cmp(&rhs)
+        });
+        if a > 0 {
+            ord
+        } else {
+            ord.reverse()
+        }
+    }
+}
+
+impl Neg for &BigDecimal {
+    type Output = BigDecimal;
+
+    /// The negation of a zero is 0, unless rounding toward negative infinity, like Python's `Decimal.__neg__()`.
+    fn neg(self) -> Self::Output {
+        let context = Context::current();
+        BigDecimal::finish(-&self.coef, self.exp, &context).with_zero_sign(!self.negative && context.rounding == Rounding::Floor)
+    }
+}
+
+impl Neg for BigDecimal {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+#[auto_impl_ops::auto_ops]
+impl Add<&BigDecimal> for &BigDecimal {
+    type Output = BigDecimal;
+
+    fn add(self, rhs: &BigDecimal) -> Self::Output {
+        let context = Context::current();
+        let (hi, lo) = if self.exp >= rhs.exp { (self, rhs) } else { (rhs, self) };
+
+        // a far smaller operand only matters as a sticky digit below the rounding position
+        let bound = hi.exp.min(hi.adjusted() - context.prec as i64 - 1) - 1;
+        let sticky;
+        let lo = if !hi.is_zero() && !lo.is_zero() && lo.adjusted() < bound {
+            sticky = BigDecimal {
+                coef: Int::from(if lo.is_negative() { -1 } else { 1 }),
+                exp: bound - 1,
+                negative: lo.negative,
+            };
+            &sticky
+        } else {
+            lo
+        };
+
+        // an exact zero sum of opposite signs is 0, unless rounding toward negative infinity
+        let negative = if self.negative == rhs.negative {
+            self.negative
+        } else {
+            context.rounding == Rounding::Floor
+        };
+        let coef = &hi.coef * pow10(hi.exp - lo.exp) + &lo.coef;
+        BigDecimal::finish(coef, lo.exp, &context).with_zero_sign(negative)
+    }
+}
+
+#[auto_impl_ops::auto_ops]
+impl Sub<&BigDecimal> for &BigDecimal {
+    type Output = BigDecimal;
+
+    fn sub(self, rhs: &BigDecimal) -> Self::Output {
+        let neg = BigDecimal {
+            coef: -&rhs.coef,
+            exp: rhs.exp,
+            negative: !rhs.negative,
+        };
+        self + &neg
+    }
+}
+
+#[auto_impl_ops::auto_ops]
+impl Mul<&BigDecimal> for &BigDecimal {
+    type Output = BigDecimal;
+
+    fn mul(self, rhs: &BigDecimal) -> Self::Output {
+        BigDecimal::finish(&self.coef * &rhs.coef, self.exp + rhs.exp, &Context::current()).with_zero_sign(self.negative != rhs.negative)
+    }
+}
+
+#[auto_impl_ops::auto_ops]
+impl Div<&BigDecimal> for &BigDecimal {
+    type Output = BigDecimal;
+
+    fn div(self, rhs: &BigDecimal) -> Self::Output {
+        detail::check_zero(rhs.coef.clone());
+
+        let context = Context::current();
+        let ideal = self.exp - rhs.exp;
+        if self.is_zero() {
+            return BigDecimal::finish(Int::new(), ideal, &context).with_zero_sign(self.negative != rhs.negative);
+        }
+
+        // scale the dividend so that the quotient has at least prec + 1 digits
+        let shift = (context.prec as i64 + rhs.coef.digits() as i64 - self.coef.digits() as i64 + 1).max(0);
+        let (mut q, r) = (&self.coef * pow10(shift)).divmod(&rhs.coef);
+        let mut exp = ideal - shift;
+
+        if !r.is_zero() {
+            // append a sticky digit, its sign follows the quotient
+            let negative = self.is_negative() != rhs.is_negative();
+            q = q * Int::from(10) + Int::from(if negative { -1 } else { 1 });
+            exp -= 1;
+        } else {
+            // exact, remove trailing zeros down to the ideal exponent
+            let ten = Int::from(10);
+            while exp < ideal {
+                let (t, r) = q.divmod(&ten);
+                if !r.is_zero() {
+                    break;
+                }
+                q = t;
+                exp += 1;
+            }
+        }
+
+        BigDecimal::finish(q, exp, &context)
+    }
+}
+
+#[auto_impl_ops::auto_ops]
+impl Rem<&BigDecimal> for &BigDecimal {
+    type Output = BigDecimal;
+
+    /// The remainder has the same sign as the dividend, like Python's `Decimal.__mod__()`.
+    /// If the integer quotient has more digits than the precision, signal `InvalidOperation`,
+    /// and panic even if it's untrapped since there is no NaN.
+    fn rem(self, rhs: &BigDecimal) -> Self::Output {
+        detail::check_zero(rhs.coef.clone());
+
+        // the quotient is at least `10**(gap - 1)`, so reject it before aligning the exponents
+        let context = Context::current();
+        let prec = context.prec as i64;
+        let impossible = || {
+            let message = "Error: Division impossible.";
+            Context::signal(Signal::InvalidOperation, message);
+            panic!("{message}");
+        };
+        if !self.is_zero() && self.adjusted() - rhs.adjusted() > prec {
+            impossible();
+        }
+
+        let exp = self.exp.min(rhs.exp);
+        let lhs = &self.coef * pow10(self.exp - exp);
+        let rhs = &rhs.coef * pow10(rhs.exp - exp);
+        if (&lhs / &rhs).digits() as i64 > prec {
+            impossible();
+        }
+        BigDecimal::finish(lhs % rhs, exp, &context).with_zero_sign(self.negative)
+    }
+}
+
+/*
+Display
+*/
+
+impl Display for BigDecimal {
+    /// Format like Python's `str(Decimal)`, using scientific notation for very large or small exponents.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let digits = self.coef.abs().to_string();
+        let len = digits.len() as i64;
+        let adjusted = self.exp + len - 1;
+        let sign = if self.negative { "-" } else { "" };
+
+        if self.exp <= 0 && adjusted >= -6 {
+            let point = len + self.exp;
+            if self.exp == 0 {
+                write!(f, "{sign}{digits}")
+            } else if point > 0 {
+                write!(f, "{sign}{}.{}", &digits[..point as usize], &digits[point as usize..])
+            } else {
+                write!(f, "{sign}0.{}{digits}", "0".repeat(-point as usize))
+            }
+        } else {
+            let (first, rest) = digits.split_at(1);
+            let point = if rest.is_empty() { "" } else { "." };
+            write!(f, "{sign}{first}{point}{rest}E{}{adjusted}", if adjusted >= 0 { "+" } else { "" })
+        }
+    }
+}
@@ -1,38 +1,191 @@
 use std::{
-    fmt::Display,
+    f64::consts::{E, FRAC_PI_2, LN_10, LN_2},
+    fmt::{Alignment, Display},
     hash::{Hash, Hasher},
-    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
     str::FromStr,
 };
 
-use regex::Regex;
+use crate::{detail, ConversionError, Decimal, Fraction, Int, Number};
 
-use crate::detail;
+// Thresholds of CPython's `cmath` module to avoid overflow and underflow.
+const LARGE_DOUBLE: f64 = f64::MAX / 4.;
+const LOG_LARGE_DOUBLE: f64 = 708.3964185322641; // ln(LARGE_DOUBLE)
+const SQRT_LARGE_DOUBLE: f64 = 6.703903964971299e153; // sqrt(LARGE_DOUBLE)
+const SQRT_DBL_MIN: f64 = 1.4916681462400413e-154; // sqrt(f64::MIN_POSITIVE)
+
+// Return `x * 2**exp`.
+fn ldexp(x: f64, exp: i32) -> f64 {
+    x * 2f64.powi(exp)
+}
+
+// The inverse hyperbolic sine of the C library used by CPython, `f64::asinh` differs in the last bit.
+fn asinh(x: f64) -> f64 {
+    let ax = x.abs();
+    let w = if !ax.is_finite() || ax < 2f64.powi(-28) {
+        return x;
+    } else if ax >= 2f64.powi(28) {
+        ax.ln() + LN_2
+    } else if ax > 2. {
+        (2. * ax + 1. / ((x * x + 1.).sqrt() + ax)).ln()
+    } else {
+        let t = x * x;
+        (ax + t / (1. + (1. + t).sqrt())).ln_1p()
+    };
+    w.copysign(x)
+}
+
+// Return the length of the longest prefix of `s` that is a float literal, like `[+-](digits[.[digits]]|.digits)[(e|E)[+-]digits]`, `inf`, `infinity` or `nan`.
+fn float_prefix(s: &[u8]) -> usize {
+    let sign = usize::from(matches!(s.first(), Some(b'+' | b'-')));
+    let rest = &s[sign..];
+    for word in ["infinity", "inf", "nan"] {
+        if rest.len() >= word.len() && rest[..word.len()].eq_ignore_ascii_case(word.as_bytes()) {
+            return sign + word.len();
+        }
+    }
+
+    let digits = |i: usize| s[i..].iter().take_while(|c| c.is_ascii_digit()).count();
+    let int = digits(sign);
+    let mut i = sign + int;
+    if s.get(i) == Some(&b'.') {
+        let dec = digits(i + 1);
+        if int + dec == 0 {
+            return 0;
+        }
+        i += 1 + dec;
+    } else if int == 0 {
+        return 0;
+    }
+
+    if matches!(s.get(i), Some(b'e' | b'E')) {
+        let j = i + 1 + usize::from(matches!(s.get(i + 1), Some(b'+' | b'-')));
+        if digits(j) > 0 {
+            i = j + digits(j);
+        }
+    }
+    i
+}
+
+// Format `x` like Python's `repr(float)` but without the trailing `.0`, or like `"%.{precision}g"` if given.
+fn float_repr(x: f64, precision: Option<usize>) -> String {
+    if x.is_nan() {
+        return "nan".to_string();
+    }
+
+    let sign = if x.is_sign_negative() { "-" } else { "" };
+    if x.is_infinite() {
+        return format!("{sign}inf");
+    }
+
+    // the shortest round-trip digits, or the correctly rounded significant digits
+    let sci = match precision {
+        Some(p) => format!("{:.*e}", p.max(1) - 1, x.abs()),
+        None => format!("{:e}", x.abs()),
+    };
+    let (mantissa, exp) = sci.split_once('e').unwrap();
+    let exp: i32 = exp.parse().unwrap();
+    let digits = mantissa.replace('.', "");
+    let digits = match digits.trim_end_matches('0') {
+        "" => "0",
+        digits => digits,
+    };
+
+    let limit = precision.map_or(16, |p| p.max(1) as i32);
+    let body = if exp < -4 || exp >= limit {
+        let (head, tail) = digits.split_at(1);
+        let point = if tail.is_empty() { "" } else { "." };
+        format!("{head}{point}{tail}e{}{:02}", if exp < 0 { '-' } else { '+' }, exp.abs())
+    } else if exp < 0 {
+        format!("0.{}{digits}", "0".repeat((-exp - 1) as usize))
+    } else if digits.len() <= exp as usize + 1 {
+        format!("{digits}{}", "0".repeat(exp as usize + 1 - digits.len()))
+    } else {
+        let (int, frac) = digits.split_at(exp as usize + 1);
+        format!("{int}.{frac}")
+    };
+    format!("{sign}{body}")
+}
 
 /// Complex provides support for complex number arithmetic.
-#[derive(Debug, Clone, Copy, Default)]
-pub struct Complex {
+///
+/// The parts are `f64` by default, `Complex<Int>` (Gaussian integers) and `Complex<Fraction>` are exact.
+#[derive(Debug, Clone, Copy)]
+pub struct Complex<T = f64> {
     // Real part.
-    real: f64,
+    real: T,
 
     // Imaginary part.
-    imag: f64,
+    imag: T,
+}
+
+impl<T: Number> Complex<T> {
+    /// Construct a new zero complex with any type of parts, e.g. `Complex::<Int>::zero()`.
+    pub fn zero() -> Self {
+        Self {
+            real: T::default(),
+            imag: T::default(),
+        }
+    }
+
+    /// Return the real part.
+    pub fn real(&self) -> T {
+        self.real.clone()
+    }
+
+    /// Return the imaginary part.
+    pub fn imag(&self) -> T {
+        self.imag.clone()
+    }
+
+    /// Return the squared absolute value of this, which is exact for exact parts.
+    pub fn norm(&self) -> T {
+        self.real.clone() * self.real.clone() + self.imag.clone() * self.imag.clone()
+    }
+
+    /// Return the conjugate value of this.
+    pub fn conjugate(&self) -> Self {
+        Self {
+            real: self.real.clone(),
+            imag: -self.imag.clone(),
+        }
+    }
 }
 
 impl Complex {
+    pub const ZERO: Self = Self { real: 0., imag: 0. };
+    pub const ONE: Self = Self { real: 1., imag: 0. };
+    pub const I: Self = Self { real: 0., imag: 1. };
+    pub const INFINITY: Self = Self { real: f64::INFINITY, imag: 0. };
+    pub const NAN: Self = Self { real: f64::NAN, imag: 0. };
+
     /// Construct a new zero complex.
     pub fn new() -> Self {
-        Self::default()
+        Self::ZERO
     }
 
-    /// Return the real part.
-    pub fn real(&self) -> f64 {
-        self.real
+    /// Construct from the modulus `r` and the phase angle `phi` (in radians), like Python's `cmath.rect()`.
+    pub fn from_polar(r: f64, phi: f64) -> Self {
+        if phi.is_infinite() && r != 0. && !r.is_nan() {
+            panic!("Error: Math domain error.");
+        }
+
+        if r == 0. && !phi.is_finite() {
+            Self::ZERO
+        } else if phi == 0. {
+            // keep the sign of zero, and avoid `inf * 0`
+            Self::from((r, if r.is_nan() { phi } else { phi * r.signum() }))
+        } else if r.is_infinite() {
+            let real = if phi.is_nan() { f64::INFINITY } else { f64::INFINITY.copysign(r * phi.cos()) };
+            Self::from((real, f64::INFINITY.copysign(r * phi.sin())))
+        } else {
+            Self::from((r * phi.cos(), r * phi.sin()))
+        }
     }
 
-    /// Return the imaginary part.
-    pub fn imag(&self) -> f64 {
-        self.imag
+    /// Construct from the modulus `r` and the phase angle `phi` (in radians), the same as [`Complex::from_polar`].
+    pub fn rect(r: f64, phi: f64) -> Self {
+        Self::from_polar(r, phi)
     }
 
     /// Return the absolute value (distance from origin) of this.
@@ -45,21 +198,63 @@ impl Complex {
         f64::atan2(self.imag, self.real)
     }
 
-    /// Return the conjugate value of this.
-    pub fn conjugate(&self) -> Self {
-        Self {
-            real: self.real,
-            imag: -self.imag,
+    /// Return the phase angle (in radians) of this, the same as [`Complex::arg`], like Python's `cmath.phase()`.
+    pub fn phase(&self) -> f64 {
+        self.arg()
+    }
+
+    /// Return the modulus and the phase angle of this, like Python's `cmath.polar()`.
+    pub fn polar(&self) -> (f64, f64) {
+        (self.abs(), self.arg())
+    }
+
+    /// Return `true` if both the real and imaginary parts are finite, like Python's `cmath.isfinite()`.
+    pub fn is_finite(&self) -> bool {
+        self.real.is_finite() && self.imag.is_finite()
+    }
+
+    /// Return `true` if either the real or the imaginary part is infinite, like Python's `cmath.isinf()`.
+    pub fn is_infinite(&self) -> bool {
+        self.real.is_infinite() || self.imag.is_infinite()
+    }
+
+    /// Return `true` if this is close to `other` within the relative and absolute tolerances, like Python's `cmath.isclose()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pyinrs::Complex;
+    /// let z = Complex::from((0.1, 0.2)) + Complex::from((0.2, 0.1));
+    /// assert!(z != Complex::from((0.3, 0.3)));
+    /// assert!(z.is_close(&Complex::from((0.3, 0.3)), 1e-9, 0.));
+    /// ```
+    pub fn is_close(&self, other: &Self, rel_tol: f64, abs_tol: f64) -> bool {
+        if rel_tol < 0. || abs_tol < 0. {
+            panic!("Error: Require non-negative tolerances.");
+        }
+
+        if self == other {
+            return true;
         }
+        if self.is_infinite() || other.is_infinite() {
+            return false;
+        }
+        let diff = (self - other).abs();
+        diff <= rel_tol * other.abs() || diff <= rel_tol * self.abs() || diff <= abs_tol
+    }
+
+    /// Return `true` if either the real or the imaginary part is NaN, like Python's `cmath.isnan()`.
+    pub fn is_nan(&self) -> bool {
+        self.real.is_nan() || self.imag.is_nan()
     }
 
     /// Return `base**exp`.
     pub fn pow(base: &Self, exp: &Self) -> Self {
-        if exp == &0.0.into() {
-            return 1.0.into();
+        if exp == &Self::ZERO {
+            return Self::ONE;
         }
 
-        if base == &0.0.into() {
+        if base == &Self::ZERO {
             panic!("Error: Math domain error.");
         }
 
@@ -71,6 +266,259 @@ impl Complex {
             imag: coef * theta.sin(),
         }
     }
+
+    // The functions below follow the algorithms of CPython's `cmath` module for finite values, including the branch cuts
+    // and the signs of zeros, and panic with `Error: Math domain error.` where Python raises `ValueError`.
+
+    // Multiply by `i`, which maps the hyperbolic functions to the trigonometric ones.
+    fn mul_i(self) -> Self {
+        Self::from((-self.imag, self.real))
+    }
+
+    // Multiply by `-i`.
+    fn mul_neg_i(self) -> Self {
+        Self::from((self.imag, -self.real))
+    }
+
+    /// Return the exponential of this, like Python's `cmath.exp()`.
+    pub fn exp(&self) -> Self {
+        if self.real > LOG_LARGE_DOUBLE {
+            let l = (self.real - 1.).exp();
+            Self::from((l * self.imag.cos() * E, l * self.imag.sin() * E))
+        } else {
+            let l = self.real.exp();
+            Self::from((l * self.imag.cos(), l * self.imag.sin()))
+        }
+    }
+
+    /// Return the logarithm of this to the given base, or the natural logarithm if `base` is `None`, like Python's `cmath.log()`.
+    /// The branch cut is along the negative real axis.
+    pub fn log(&self, base: Option<Self>) -> Self {
+        let (ax, ay) = (self.real.abs(), self.imag.abs());
+        let real = if ax > LARGE_DOUBLE || ay > LARGE_DOUBLE {
+            (ax / 2.).hypot(ay / 2.).ln() + LN_2
+        } else if ax < f64::MIN_POSITIVE && ay < f64::MIN_POSITIVE {
+            if ax == 0. && ay == 0. {
+                panic!("Error: Math domain error.");
+            }
+            ldexp(ax, f64::MANTISSA_DIGITS as i32).hypot(ldexp(ay, f64::MANTISSA_DIGITS as i32)).ln() - f64::MANTISSA_DIGITS as f64 * LN_2
+        } else {
+            let h = ax.hypot(ay);
+            if (0.71..=1.73).contains(&h) {
+                let (am, an) = if ax > ay { (ax, ay) } else { (ay, ax) };
+                ((am - 1.) * (am + 1.) + an * an).ln_1p() / 2.
+            } else {
+                h.ln()
+            }
+        };
+
+        let ln = Self::from((real, self.imag.atan2(self.real)));
+        match base {
+            Some(base) => ln / base.log(None),
+            None => ln,
+        }
+    }
+
+    /// Return the base-10 logarithm of this, like Python's `cmath.log10()`.
+    pub fn log10(&self) -> Self {
+        let ln = self.log(None);
+        Self::from((ln.real / LN_10, ln.imag / LN_10))
+    }
+
+    /// Return the square root of this, like Python's `cmath.sqrt()`.
+    /// The branch cut is along the negative real axis.
+    pub fn sqrt(&self) -> Self {
+        if self.real == 0. && self.imag == 0. {
+            return Self::from((0., self.imag));
+        }
+
+        let (mut ax, ay) = (self.real.abs(), self.imag.abs());
+        let s = if ax < f64::MIN_POSITIVE && ay < f64::MIN_POSITIVE {
+            // hypot(ax, ay) may be subnormal
+            ax = ldexp(ax, 53);
+            ldexp((ax + ax.hypot(ldexp(ay, 53))).sqrt(), -27)
+        } else {
+            ax /= 8.;
+            2. * (ax + ax.hypot(ay / 8.)).sqrt()
+        };
+        let d = ay / (2. * s);
+
+        if self.real >= 0. {
+            Self::from((s, d.copysign(self.imag)))
+        } else {
+            Self::from((d, s.copysign(self.imag)))
+        }
+    }
+
+    /// Return the sine of this, like Python's `cmath.sin()`.
+    pub fn sin(&self) -> Self {
+        self.mul_i().sinh().mul_neg_i()
+    }
+
+    /// Return the cosine of this, like Python's `cmath.cos()`.
+    pub fn cos(&self) -> Self {
+        self.mul_i().cosh()
+    }
+
+    /// Return the tangent of this, like Python's `cmath.tan()`.
+    pub fn tan(&self) -> Self {
+        self.mul_i().tanh().mul_neg_i()
+    }
+
+    /// Return the arc sine of this, like Python's `cmath.asin()`.
+    /// The branch cuts are along the real axis beyond -1 and 1.
+    pub fn asin(&self) -> Self {
+        self.mul_i().asinh().mul_neg_i()
+    }
+
+    /// Return the arc cosine of this, like Python's `cmath.acos()`.
+    /// The branch cuts are along the real axis beyond -1 and 1.
+    pub fn acos(&self) -> Self {
+        let (x, y) = (self.real, self.imag);
+        if x.abs() > LARGE_DOUBLE || y.abs() > LARGE_DOUBLE {
+            let l = (x / 2.).hypot(y / 2.).ln() + LN_2 * 2.;
+            let imag = if x < 0. { -l.copysign(y) } else { l.copysign(-y) };
+            return Self::from((y.abs().atan2(x), imag));
+        }
+
+        let s1 = Self::from((1. - x, -y)).sqrt();
+        let s2 = Self::from((1. + x, y)).sqrt();
+        Self::from((2. * s1.real.atan2(s2.real), asinh(s2.real * s1.imag - s2.imag * s1.real)))
+    }
+
+    /// Return the arc tangent of this, like Python's `cmath.atan()`.
+    /// The branch cuts are along the imaginary axis beyond -i and i.
+    pub fn atan(&self) -> Self {
+        self.mul_i().atanh().mul_neg_i()
+    }
+
+    /// Return the hyperbolic sine of this, like Python's `cmath.sinh()`.
+    pub fn sinh(&self) -> Self {
+        let (x, y) = (self.real, self.imag);
+        if x.abs() > LOG_LARGE_DOUBLE {
+            let x = x - 1f64.copysign(x);
+            Self::from((y.cos() * x.sinh() * E, y.sin() * x.cosh() * E))
+        } else {
+            Self::from((y.cos() * x.sinh(), y.sin() * x.cosh()))
+        }
+    }
+
+    /// Return the hyperbolic cosine of this, like Python's `cmath.cosh()`.
+    pub fn cosh(&self) -> Self {
+        let (x, y) = (self.real, self.imag);
+        if x.abs() > LOG_LARGE_DOUBLE {
+            let x = x - 1f64.copysign(x);
+            Self::from((y.cos() * x.cosh() * E, y.sin() * x.sinh() * E))
+        } else {
+            Self::from((y.cos() * x.cosh(), y.sin() * x.sinh()))
+        }
+    }
+
+    /// Return the hyperbolic tangent of this, like Python's `cmath.tanh()`.
+    pub fn tanh(&self) -> Self {
+        let (x, y) = (self.real, self.imag);
+        if x.abs() > LOG_LARGE_DOUBLE {
+            return Self::from((1f64.copysign(x), 4. * y.sin() * y.cos() * (-2. * x.abs()).exp()));
+        }
+
+        let (tx, ty, cx) = (x.tanh(), y.tan(), 1. / x.cosh());
+        let txty = tx * ty;
+        let den = 1. + txty * txty;
+        Self::from((tx * (1. + ty * ty) / den, ((ty / den) * cx) * cx))
+    }
+
+    /// Return the inverse hyperbolic sine of this, like Python's `cmath.asinh()`.
+    /// The branch cuts are along the imaginary axis beyond -i and i.
+    pub fn asinh(&self) -> Self {
+        let (x, y) = (self.real, self.imag);
+        if x.abs() > LARGE_DOUBLE || y.abs() > LARGE_DOUBLE {
+            let l = (x / 2.).hypot(y / 2.).ln() + LN_2 * 2.;
+            let real = if y >= 0. { l.copysign(x) } else { -l.copysign(-x) };
+            return Self::from((real, y.atan2(x.abs())));
+        }
+
+        let s1 = Self::from((1. + y, -x)).sqrt();
+        let s2 = Self::from((1. - y, x)).sqrt();
+        Self::from((asinh(s1.real * s2.imag - s2.real * s1.imag), y.atan2(s1.real * s2.real - s1.imag * s2.imag)))
+    }
+
+    /// Return the inverse hyperbolic cosine of this, like Python's `cmath.acosh()`.
+    /// The branch cut is along the real axis less than 1.
+    pub fn acosh(&self) -> Self {
+        let (x, y) = (self.real, self.imag);
+        if x.abs() > LARGE_DOUBLE || y.abs() > LARGE_DOUBLE {
+            return Self::from(((x / 2.).hypot(y / 2.).ln() + LN_2 * 2., y.atan2(x)));
+        }
+
+        let s1 = Self::from((x - 1., y)).sqrt();
+        let s2 = Self::from((x + 1., y)).sqrt();
+        Self::from((asinh(s1.real * s2.real + s1.imag * s2.imag), 2. * s1.imag.atan2(s2.real)))
+    }
+
+    /// Return the inverse hyperbolic tangent of this, like Python's `cmath.atanh()`.
+    /// The branch cuts are along the real axis beyond -1 and 1.
+    pub fn atanh(&self) -> Self {
+        // atanh is odd, reduce to the right half plane
+        if self.real < 0. {
+            return -(-*self).atanh();
+        }
+
+        let (x, y, ay) = (self.real, self.imag, self.imag.abs());
+        if x > SQRT_LARGE_DOUBLE || ay > SQRT_LARGE_DOUBLE {
+            let h = (x / 2.).hypot(y / 2.);
+            Self::from((x / 4. / h / h, -FRAC_PI_2.copysign(-y)))
+        } else if x == 1. && ay < SQRT_DBL_MIN {
+            if ay == 0. {
+                panic!("Error: Math domain error.");
+            }
+            Self::from((-(ay.sqrt() / ay.hypot(2.).sqrt()).ln(), (2f64.atan2(-ay) / 2.).copysign(y)))
+        } else {
+            Self::from((
+                (4. * x / ((1. - x) * (1. - x) + ay * ay)).ln_1p() / 4.,
+                -(-2. * y).atan2((1. - x) * (1. + x) - ay * ay) / 2.,
+            ))
+        }
+    }
+}
+
+impl Complex<Int> {
+    /// Return the quotient and remainder of the Gaussian integer division simultaneously.
+    /// The quotient is rounded to the nearest, so `(self % rhs).norm() <= rhs.norm() / 2`.
+    pub fn divmod(&self, rhs: &Self) -> (Self, Self) {
+        let norm = rhs.norm();
+        detail::check_zero(norm.clone());
+
+        // round(x / norm) == floor((2x + norm) / 2norm)
+        let twice = &norm + &norm;
+        let round = |x: Int| {
+            let (q, r) = (&x + &x + &norm).divmod(&twice);
+            if r.is_negative() {
+                q - Int::from(1)
+            } else {
+                q
+            }
+        };
+
+        let num = self * rhs.conjugate();
+        let quotient = Self::from((round(num.real), round(num.imag)));
+        let remainder = self - &quotient * rhs;
+        (quotient, remainder)
+    }
+
+    /// Calculate the greatest common divisor of two Gaussian integers.
+    /// The result is the associate with a positive real part and a non-negative imaginary part.
+    pub fn gcd(a: &Self, b: &Self) -> Self {
+        let mut g = detail::gcd(a.clone(), b.clone());
+        if g.real.is_zero() && g.imag.is_zero() {
+            return g;
+        }
+
+        // multiply by the unit `i` until it's in the first quadrant
+        while g.real <= Int::new() || g.imag.is_negative() {
+            g = Self::from((-g.imag, g.real));
+        }
+        g
+    }
 }
 
 /*
@@ -83,33 +531,121 @@ impl From<f64> for Complex {
     }
 }
 
-impl From<(f64, f64)> for Complex {
-    fn from(value: (f64, f64)) -> Self {
+macro_rules! impl_from_primitive {
+    ($($t:ty),+ $(,)?) => { $(
+        impl From<$t> for Complex {
+            fn from(value: $t) -> Self {
+                Self::from(value as f64)
+            }
+        }
+    )+ };
+}
+
+impl_from_primitive!(f32, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl<T: Number> From<(T, T)> for Complex<T> {
+    fn from(value: (T, T)) -> Self {
         Self { real: value.0, imag: value.1 }
     }
 }
 
+impl From<Fraction> for Complex {
+    fn from(value: Fraction) -> Self {
+        Self::from(f64::from(value))
+    }
+}
+
+impl From<Decimal> for Complex {
+    fn from(value: Decimal) -> Self {
+        Self::from(f64::from(value))
+    }
+}
+
+impl From<&Int> for Complex {
+    fn from(value: &Int) -> Self {
+        Self::from(f64::from(value))
+    }
+}
+
+impl From<Int> for Complex {
+    fn from(value: Int) -> Self {
+        Self::from(&value)
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct ParseComplexError;
 
+impl Default for Complex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl FromStr for Complex {
     type Err = ParseComplexError;
 
+    /// Parse a complex like Python's `complex(str)`, e.g. `"1+2j"`, `"(1e3-2J)"`, `"-j"`, `"inf+nanj"` or `"1_000j"`.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.trim();
-
-        let re = Regex::new(r"^([-+]?\d*\.?\d*)([-+]?\d*\.?\d*)j?$").unwrap();
-        if let Some(caps) = re.captures(s) {
-            let mut real = caps[1].parse().unwrap_or_default();
-            let mut imag = caps[2].parse().unwrap_or_default();
-            if s.ends_with('j') && caps[2].is_empty() {
-                imag = real;
-                real = 0.0;
+        // underscores are only allowed between digits
+        let bytes = s.as_bytes();
+        let mut chars = Vec::with_capacity(bytes.len());
+        for (i, &c) in bytes.iter().enumerate() {
+            if c != b'_' {
+                chars.push(c);
+            } else if i == 0 || i + 1 == bytes.len() || !bytes[i - 1].is_ascii_digit() || !bytes[i + 1].is_ascii_digit() {
+                return Err(ParseComplexError);
             }
-            return Ok(Self { real, imag });
         }
 
-        Err(ParseComplexError)
+        let s = std::str::from_utf8(&chars).map_err(|_| ParseComplexError)?.trim();
+        let s = match s.strip_prefix('(') {
+            Some(inner) => inner.strip_suffix(')').ok_or(ParseComplexError)?.trim(),
+            None => s,
+        };
+
+        let is_j = |c: Option<&u8>| matches!(c, Some(b'j' | b'J'));
+        let float = |s: &str| s.parse::<f64>().map_err(|_| ParseComplexError);
+        let s = s.as_bytes();
+        let n = float_prefix(s);
+        let text = |range: std::ops::Range<usize>| std::str::from_utf8(&s[range]).unwrap();
+
+        let (real, imag, end) = if n == 0 {
+            // <sign>j
+            let sign = usize::from(matches!(s.first(), Some(b'+' | b'-')));
+            if !is_j(s.get(sign)) {
+                return Err(ParseComplexError);
+            }
+            (0., if s.first() == Some(&b'-') { -1. } else { 1. }, sign + 1)
+        } else if matches!(s.get(n), Some(b'+' | b'-')) {
+            // <float><signed-float>j or <float><sign>j
+            let m = float_prefix(&s[n..]);
+            let imag = if m == 0 {
+                if s[n] == b'-' {
+                    -1.
+                } else {
+                    1.
+                }
+            } else {
+                float(text(n..n + m))?
+            };
+            let m = m.max(1);
+            if !is_j(s.get(n + m)) {
+                return Err(ParseComplexError);
+            }
+            (float(text(0..n))?, imag, n + m + 1)
+        } else if is_j(s.get(n)) {
+            // <float>j
+            (0., float(text(0..n))?, n + 1)
+        } else {
+            // <float>
+            (float(text(0..n))?, 0., n)
+        };
+
+        if end != s.len() {
+            return Err(ParseComplexError);
+        }
+        Ok(Self { real, imag })
     }
 }
 
@@ -117,23 +653,56 @@ impl FromStr for Complex {
 Function
 */
 
-impl PartialEq for Complex {
+impl<T: Number> PartialEq for Complex<T> {
     fn eq(&self, other: &Self) -> bool {
-        let epsilon = f64::EPSILON;
-        (self.real - other.real).abs() < epsilon && (self.imag - other.imag).abs() < epsilon
+        self.real == other.real && self.imag == other.imag
     }
 }
 
+// NaN is not equal to itself, which is accepted like `float` in Python's `set` and `dict`.
 impl Eq for Complex {}
 
 impl Hash for Complex {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.real.to_bits().hash(state);
-        self.imag.to_bits().hash(state);
+        // -0.0 == 0.0, so they must have the same hash
+        let bits = |x: f64| if x == 0. { 0 } else { x.to_bits() };
+        bits(self.real).hash(state);
+        bits(self.imag).hash(state);
     }
 }
 
-impl Neg for Complex {
+macro_rules! impl_exact {
+    ($($t:ty),+ $(,)?) => { $(
+        impl Default for Complex<$t> {
+            fn default() -> Self {
+                Self::zero()
+            }
+        }
+
+        impl Eq for Complex<$t> {}
+
+        impl Hash for Complex<$t> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.real.hash(state);
+                self.imag.hash(state);
+            }
+        }
+
+        impl Display for Complex<$t> {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                let imag = self.imag.to_string();
+                match imag.strip_prefix('-') {
+                    Some(abs) => write!(f, "({}-{}j)", self.real, abs),
+                    None => write!(f, "({}+{}j)", self.real, imag),
+                }
+            }
+        }
+    )+ };
+}
+
+impl_exact!(Int, Fraction);
+
+impl<T: Number> Neg for Complex<T> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
@@ -145,7 +714,7 @@ impl Neg for Complex {
 }
 
 #[auto_impl_ops::auto_ops]
-impl Add for Complex {
+impl<T: Number> Add for Complex<T> {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
@@ -154,7 +723,7 @@ impl Add for Complex {
 }
 
 #[auto_impl_ops::auto_ops]
-impl Sub for Complex {
+impl<T: Number> Sub for Complex<T> {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
@@ -163,11 +732,12 @@ impl Sub for Complex {
 }
 
 #[auto_impl_ops::auto_ops]
-impl Mul for Complex {
+impl<T: Number> Mul for Complex<T> {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        Self::from((self.real * rhs.real - self.imag * rhs.imag, self.real * rhs.imag + self.imag * rhs.real))
+        let (a, b, c, d) = (self.real, self.imag, rhs.real, rhs.imag);
+        Self::from((a.clone() * c.clone() - b.clone() * d.clone(), a * d + b * c))
     }
 }
 
@@ -176,22 +746,128 @@ impl Div for Complex {
     type Output = Self;
 
     fn div(self, rhs: Self) -> Self::Output {
-        let den = rhs.real * rhs.real + rhs.imag * rhs.imag;
-        detail::check_zero(den);
+        if rhs.real == 0. && rhs.imag == 0. {
+            panic!("Error: Divide by zero.");
+        }
+
+        // scale by the larger part of the divisor to avoid overflow, like CPython
+        let (a, b) = (self, rhs);
+        if b.real.abs() >= b.imag.abs() {
+            let ratio = b.imag / b.real;
+            let den = b.real + b.imag * ratio;
+            Self::from(((a.real + a.imag * ratio) / den, (a.imag - a.real * ratio) / den))
+        } else if b.imag.abs() >= b.real.abs() {
+            let ratio = b.real / b.imag;
+            let den = b.real * ratio + b.imag;
+            Self::from(((a.real * ratio + a.imag) / den, (a.imag * ratio - a.real) / den))
+        } else {
+            // at least one part of the divisor is NaN
+            Self::from((f64::NAN, f64::NAN))
+        }
+    }
+}
+
+#[auto_impl_ops::auto_ops]
+impl Div for Complex<Fraction> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let norm = rhs.norm();
+        detail::check_zero(norm);
+
+        let num = self * rhs.conjugate();
+        Self::from((num.real / norm, num.imag / norm))
+    }
+}
+
+#[auto_impl_ops::auto_ops]
+impl Div for Complex<Int> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.divmod(&rhs).0
+    }
+}
 
-        Self::from((
-            (self.real * rhs.real + self.imag * rhs.imag) / den,
-            (self.imag * rhs.real - self.real * rhs.imag) / den,
-        ))
+#[auto_impl_ops::auto_ops]
+impl Rem for Complex<Int> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.divmod(&rhs).1
     }
 }
 
+macro_rules! impl_primitive_ops {
+    ($($t:ty),+ $(,)?) => { $(
+        detail::impl_mixed_ops!($t => Complex, Complex::from);
+    )+ };
+}
+
+impl_primitive_ops!(f64, f32, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+detail::impl_mixed_ops!(Int => Complex, Complex::from);
+detail::impl_mixed_ops!(Fraction => Complex, Complex::from);
+detail::impl_mixed_ops!(Decimal => Complex, Complex::from);
+
 /*
 Display
 */
 
 impl Display for Complex {
+    /// Format like Python's `repr(complex)`, or `format(complex, ".{precision}")` if a precision is given.
+    /// The width applies to the whole string and it is right-aligned by default.
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "({}{:+}j)", self.real, self.imag)
+        let part = |x: f64, signed: bool| match float_repr(x, f.precision()) {
+            s if signed && !s.starts_with('-') => format!("+{s}"),
+            s => s,
+        };
+
+        // a positive zero real part is omitted without parentheses
+        let s = if self.real == 0. && self.real.is_sign_positive() {
+            format!("{}j", part(self.imag, f.sign_plus()))
+        } else {
+            format!("({}{}j)", part(self.real, f.sign_plus()), part(self.imag, true))
+        };
+
+        let padding = f.width().unwrap_or(0).saturating_sub(s.chars().count());
+        let (left, right) = match f.align() {
+            Some(Alignment::Left) => (0, padding),
+            Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+            _ => (padding, 0),
+        };
+        let fill = f.fill().to_string();
+        write!(f, "{}{s}{}", fill.repeat(left), fill.repeat(right))
+    }
+}
+
+/*
+Transform
+*/
+
+impl TryFrom<Complex> for f64 {
+    type Error = ConversionError;
+
+    fn try_from(value: Complex) -> Result<Self, Self::Error> {
+        if value.imag != 0.0 {
+            return Err(ConversionError::NotReal);
+        }
+
+        Ok(value.real)
+    }
+}
+
+impl TryFrom<Complex> for Fraction {
+    type Error = ConversionError;
+
+    fn try_from(value: Complex) -> Result<Self, Self::Error> {
+        Fraction::from_float(f64::try_from(value)?)
+    }
+}
+
+impl TryFrom<Complex> for Decimal {
+    type Error = ConversionError;
+
+    fn try_from(value: Complex) -> Result<Self, Self::Error> {
+        Fraction::try_from(value).map(Decimal::from)
     }
 }
@@ -0,0 +1,225 @@
+use std::{cell::RefCell, cmp::Ordering};
+
+/// Rounding modes, the same as those in Python's `decimal` module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Rounding {
+    /// Round towards positive infinity, `ROUND_CEILING`.
+    Ceiling,
+
+    /// Round towards zero, `ROUND_DOWN`.
+    Down,
+
+    /// Round towards negative infinity, `ROUND_FLOOR`.
+    Floor,
+
+    /// Round to nearest with ties going towards zero, `ROUND_HALF_DOWN`.
+    HalfDown,
+
+    /// Round to nearest with ties going to nearest even integer, `ROUND_HALF_EVEN`. This is the default.
+    #[default]
+    HalfEven,
+
+    /// Round to nearest with ties going away from zero, `ROUND_HALF_UP`.
+    HalfUp,
+
+    /// Round away from zero, `ROUND_UP`.
+    Up,
+
+    /// Round away from zero if the last digit after rounding towards zero would have been 0 or 5, otherwise towards zero, `ROUND_05UP`.
+    Up05,
+}
+
+impl Rounding {
+    // Decide whether to increase the magnitude truncated towards zero by one unit.
+    // `last` is the last kept digit, `half` is the comparison of the discarded part with half a unit.
+    pub(crate) fn round_up(self, negative: bool, last: u8, half: Ordering, inexact: bool) -> bool {
+        match self {
+            Rounding::Ceiling => inexact && !negative,
+            Rounding::Down => false,
+            Rounding::Floor => inexact && negative,
+            Rounding::HalfDown => half.is_gt(),
+            Rounding::HalfEven => half.is_gt() || half.is_eq() && last % 2 == 1,
+            Rounding::HalfUp => half.is_ge(),
+            Rounding::Up => inexact,
+            Rounding::Up05 => inexact && (last == 0 || last == 5),
+        }
+    }
+}
+
+/// Exceptional conditions of arithmetic operations, the same as those signals in Python's `decimal` module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Signal {
+    /// A non-zero number is divided by zero, the result is an infinity if not trapped.
+    DivisionByZero,
+
+    /// Non-zero digits are discarded by rounding.
+    Inexact,
+
+    /// Digits are discarded by rounding, even if they are all zeros.
+    Rounded,
+
+    /// The result is too large to represent, the result is an infinity if not trapped.
+    Overflow,
+
+    /// The operation is invalid, like `0/0` or `sqrt(-1)`, the result is NaN if not trapped.
+    InvalidOperation,
+}
+
+/// A set of signals, used as the flags and traps of a context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Signals(u8);
+
+impl Signals {
+    /// Construct a new empty set of signals.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return `true` if the set contains the signal.
+    pub fn contains(&self, signal: Signal) -> bool {
+        self.0 & 1 << signal as u8 != 0
+    }
+
+    /// Add the signal to the set.
+    pub fn insert(&mut self, signal: Signal) {
+        self.0 |= 1 << signal as u8;
+    }
+
+    /// Remove the signal from the set.
+    pub fn remove(&mut self, signal: Signal) {
+        self.0 &= !(1 << signal as u8);
+    }
+
+    /// Return `true` if the set contains no signals.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<const N: usize> From<[Signal; N]> for Signals {
+    fn from(signals: [Signal; N]) -> Self {
+        signals.into_iter().collect()
+    }
+}
+
+impl FromIterator<Signal> for Signals {
+    fn from_iter<T: IntoIterator<Item = Signal>>(iter: T) -> Self {
+        let mut signals = Self::new();
+        iter.into_iter().for_each(|signal| signals.insert(signal));
+        signals
+    }
+}
+
+/// Context for arithmetic operations, like Python's `decimal.Context`.
+///
+/// Each thread has its own current context, see [`Context::current`] and [`Context::set`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Context {
+    /// Number of significant digits, at least 1.
+    pub prec: usize,
+
+    /// Rounding mode.
+    pub rounding: Rounding,
+
+    /// Minimum adjusted exponent, at most 0.
+    pub emin: i64,
+
+    /// Maximum adjusted exponent, at least 0.
+    pub emax: i64,
+
+    /// Signals that panic when raised, otherwise the operation returns a special value.
+    pub traps: Signals,
+
+    /// Signals raised since the flags were cleared.
+    pub flags: Signals,
+}
+
+thread_local! {
+    static CONTEXT: RefCell<Context> = RefCell::new(Context::default());
+}
+
+// Restore the previous context when dropped, even if panicked.
+struct Restore(Option<Context>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        if let Some(context) = self.0.take() {
+            Context::set(context);
+        }
+    }
+}
+
+impl Context {
+    /// Return a copy of the current context of this thread, like Python's `decimal.getcontext()`.
+    pub fn current() -> Self {
+        CONTEXT.with(|c| c.borrow().clone())
+    }
+
+    /// Set the current context of this thread, like Python's `decimal.setcontext()`.
+    pub fn set(context: Self) {
+        if context.prec < 1 || context.emin > 0 || context.emax < 0 {
+            panic!("Error: Require prec >= 1 and emin <= 0 <= emax for context.");
+        }
+
+        CONTEXT.with(|c| *c.borrow_mut() = context);
+    }
+
+    /// Run `f` with `context` as the current context, then restore the previous one, like Python's `decimal.localcontext()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pyinrs::{BigDecimal, Context};
+    /// let third = Context::local(Context { prec: 5, ..Context::default() }, || BigDecimal::from(1) / BigDecimal::from(3));
+    /// assert_eq!(third.to_string(), "0.33333");
+    /// assert_eq!(Context::current().prec, 28);
+    /// ```
+    pub fn local<R>(context: Self, f: impl FnOnce() -> R) -> R {
+        let _restore = Restore(Some(Self::current()));
+        Self::set(context);
+        f()
+    }
+
+    /// Clear the flags of the current context of this thread, like Python's `Context.clear_flags()`.
+    pub fn clear_flags() {
+        CONTEXT.with(|c| c.borrow_mut().flags = Signals::new());
+    }
+
+    // Record the signal in the flags of the current context, panic with the message if it is trapped.
+    pub(crate) fn signal(signal: Signal, message: &str) {
+        let trapped = CONTEXT.with(|c| {
+            let mut context = c.borrow_mut();
+            context.flags.insert(signal);
+            context.traps.contains(signal)
+        });
+        if trapped {
+            panic!("{message}");
+        }
+    }
+
+    // Signal `Rounded` and `Inexact` for a result that is not exact.
+    pub(crate) fn signal_inexact() {
+        Self::signal(Signal::Rounded, "Error: Rounded result.");
+        Self::signal(Signal::Inexact, "Error: Inexact result.");
+    }
+
+    // The minimum exponent of subnormal numbers.
+    pub(crate) fn etiny(&self) -> i64 {
+        self.emin - self.prec as i64 + 1
+    }
+}
+
+impl Default for Context {
+    /// The default context of Python's `decimal` module: 28 digits, `HalfEven`, exponent in [-999999, 999999],
+    /// trapping `InvalidOperation`, `DivisionByZero` and `Overflow`.
+    fn default() -> Self {
+        Self {
+            prec: 28,
+            rounding: Rounding::HalfEven,
+            emin: -999999,
+            emax: 999999,
+            traps: Signals::from([Signal::InvalidOperation, Signal::DivisionByZero, Signal::Overflow]),
+            flags: Signals::new(),
+        }
+    }
+}
@@ -1,60 +1,1148 @@
 use std::{
     cmp::Ordering,
-    fmt::Display,
+    fmt::{Alignment, Display, LowerExp, UpperExp},
+    hash::{Hash, Hasher},
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
     str::FromStr,
 };
 
-use regex::Regex;
+use crate::{detail, Context, ConversionError, Fraction, Int, Rounding, Signal};
 
-use crate::Fraction;
+// Fixed-point helpers for the transcendental functions, `x` is represented by the integer `x * s` where `s = 10**w`.
 
-/// Decimal provides decimal arithmetic with repeating support.
+// Return `10**n`.
+fn pow10(n: i64) -> Int {
+    Int::pow(&Int::from(10), &Int::from(n))
+}
+
+// exp(x) for |x| <= 1/2 by Taylor series.
+fn exp_series(x: &Int, s: &Int) -> Int {
+    let (mut sum, mut term, mut k) = (Int::new(), s.clone(), 0);
+    while !term.is_zero() {
+        sum += &term;
+        k += 1;
+        term = &term * x / (s * Int::from(k));
+    }
+    sum
+}
+
+// exp(x) * 10**w, with a relative error of a few units in 10**w.
+fn exp_fixed(x: &Int, w: i64) -> Int {
+    // exp(x) = exp(x / 2**k)**(2**k), the squarings lose about 0.3k digits
+    let k = (x.abs() / pow10(w)).digits() as i64 * 10 / 3 + 1;
+    let extra = k * 3 / 10 + 3;
+    let s = pow10(w + extra);
+    let r = x.abs() * pow10(extra) / Int::pow(&Int::from(2), &Int::from(k));
+    let mut y = exp_series(&r, &s);
+    for _ in 0..k {
+        y = &y * &y / &s;
+    }
+    if x.is_negative() {
+        y = &s * &s / y;
+    }
+    y / pow10(extra)
+}
+
+// (sin(x), cos(x)) for |x| <= 2 by Taylor series.
+fn sin_cos_series(x: &Int, s: &Int) -> (Int, Int) {
+    let (mut sin, mut cos, mut term, mut k) = (Int::new(), Int::new(), s.clone(), 0);
+    while !term.is_zero() {
+        match k % 4 {
+            0 => cos += &term,
+            1 => sin += &term,
+            2 => cos -= &term,
+            _ => sin -= &term,
+        }
+        k += 1;
+        term = &term * x / (s * Int::from(k));
+    }
+    (sin, cos)
+}
+
+// atan(x), or atanh(x) if `hyperbolic`, for |x| < 1 by Taylor series.
+fn atan_series(x: &Int, s: &Int, hyperbolic: bool) -> Int {
+    let x2 = x * x / s;
+    let (mut sum, mut power, mut k) = (Int::new(), x.clone(), 0);
+    while !power.is_zero() {
+        let term = &power / Int::from(2 * k + 1);
+        if hyperbolic || k % 2 == 0 {
+            sum += &term;
+        } else {
+            sum -= &term;
+        }
+        power = &power * &x2 / s;
+        k += 1;
+    }
+    sum
+}
+
+// pi * 10**w by Machin's formula, pi = 16 atan(1/5) - 4 atan(1/239).
+fn pi(w: i64) -> Int {
+    let s = pow10(w + 3);
+    let a = atan_series(&(&s / Int::from(5)), &s, false);
+    let b = atan_series(&(&s / Int::from(239)), &s, false);
+    (a * Int::from(16) - b * Int::from(4)) / Int::from(1000)
+}
+
+// ln(2) * 10**w, ln(2) = 2 atanh(1/3).
+fn ln2(w: i64) -> Int {
+    let s = pow10(w + 3);
+    atan_series(&(&s / Int::from(3)), &s, true) * Int::from(2) / Int::from(1000)
+}
+
+// Kind of a decimal value.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+enum Kind {
+    #[default]
+    Finite,
+    Infinite,
+    QNaN,
+    SNaN,
+}
+
+/// Decimal provides decimal arithmetic with repeating support.
+///
+/// Like Python's `Decimal`, it also has `Infinity`, quiet and signaling `NaN` and signed zero.
+/// Numbers compare like Python, but unlike Python a `NaN` equals itself and is ordered, so that `Decimal` is `Eq` and `Ord`.
+/// Use [`Decimal::is_nan()`] to test for `NaN`.
+#[derive(Debug, Clone, Copy, Default)]
 pub struct Decimal {
+    // Exact value if finite, the payload if NaN.
     value: Fraction,
+
+    // Kind of value.
+    kind: Kind,
+
+    // Sign bit, which also tells -0, -Infinity and -NaN.
+    negative: bool,
 }
 
 impl Decimal {
-    pub const MAX: Self = Self { value: Fraction::MAX };
-    pub const MIN: Self = Self { value: Fraction::MIN };
-    pub const EPSILON: Self = Self { value: Fraction::EPSILON };
+    pub const MAX: Self = Self::special(Fraction::MAX, Kind::Finite, false);
+    pub const MIN: Self = Self::special(Fraction::MIN, Kind::Finite, true);
+    pub const EPSILON: Self = Self::special(Fraction::EPSILON, Kind::Finite, false);
+    pub const INFINITY: Self = Self::special(Fraction::ZERO, Kind::Infinite, false);
+    pub const NEG_INFINITY: Self = Self::special(Fraction::ZERO, Kind::Infinite, true);
+    pub const NAN: Self = Self::special(Fraction::ZERO, Kind::QNaN, false);
+
+    const fn special(value: Fraction, kind: Kind, negative: bool) -> Self {
+        Self { value, kind, negative }
+    }
+
+    // Construct a finite value, a zero takes the sign bit.
+    fn signed(value: Fraction, negative: bool) -> Self {
+        Self {
+            value,
+            kind: Kind::Finite,
+            negative: if value == Fraction::ZERO { negative } else { value < Fraction::ZERO },
+        }
+    }
+
+    // The quiet NaN result if any operand is NaN, a signaling NaN wins, then the first one, like Python.
+    // A signaling NaN signals `InvalidOperation`.
+    fn propagate(operands: &[&Self]) -> Option<Self> {
+        if operands.iter().any(|x| x.is_snan()) {
+            Context::signal(Signal::InvalidOperation, "Error: Invalid operation.");
+        }
+        let nan = operands.iter().find(|x| x.is_snan()).or_else(|| operands.iter().find(|x| x.is_nan()));
+        nan.map(|x| Self { kind: Kind::QNaN, ..**x })
+    }
+
+    // Signal `InvalidOperation` with the message, return NaN if not trapped.
+    fn invalid(message: &str) -> Self {
+        Context::signal(Signal::InvalidOperation, message);
+        Self::NAN
+    }
+
+    // Signal `Overflow`, the result is an infinity if not trapped.
+    fn overflow(negative: bool) -> Self {
+        Context::signal(Signal::Overflow, "Error: The result does not fit in a decimal.");
+        Self::special(Fraction::ZERO, Kind::Infinite, negative)
+    }
+
+    // Signal `Rounded` and `Inexact` if the result differs from the exact value.
+    fn inexact(self, exact: &Self) -> Self {
+        if self.value != exact.value {
+            Context::signal_inexact();
+        }
+        self
+    }
+
+    // Panic if self is infinite or NaN.
+    fn check_finite(&self) {
+        if !self.is_finite() {
+            panic!("Error: Cannot convert infinity or NaN to a number.");
+        }
+    }
 
     /// Construct a new zero decimal.
     pub fn new() -> Self {
         Self::default()
     }
 
-    /// Convert to fraction.
+    /// Return `true` if self is a quiet or signaling NaN.
+    pub fn is_nan(&self) -> bool {
+        matches!(self.kind, Kind::QNaN | Kind::SNaN)
+    }
+
+    /// Return `true` if self is a quiet NaN.
+    pub fn is_qnan(&self) -> bool {
+        self.kind == Kind::QNaN
+    }
+
+    /// Return `true` if self is a signaling NaN.
+    pub fn is_snan(&self) -> bool {
+        self.kind == Kind::SNaN
+    }
+
+    /// Return `true` if self is positive or negative infinity.
+    pub fn is_infinite(&self) -> bool {
+        self.kind == Kind::Infinite
+    }
+
+    /// Return `true` if self is neither infinite nor NaN.
+    pub fn is_finite(&self) -> bool {
+        self.kind == Kind::Finite
+    }
+
+    /// Return `true` if self has a negative sign, including -0, -Infinity and -NaN.
+    pub fn is_signed(&self) -> bool {
+        self.negative
+    }
+
+    /// Return `true` if self is a positive or negative zero.
+    pub fn is_zero(&self) -> bool {
+        self.is_finite() && self.value == Fraction::ZERO
+    }
+
+    /// Convert to fraction, panic if self is infinite or NaN.
     pub fn as_fraction(&self) -> Fraction {
+        self.check_finite();
         self.value
     }
 
     /// Return the absolute value.
     pub fn abs(&self) -> Self {
-        Self::from(self.value.abs())
+        Self {
+            value: if self.is_finite() { self.value.abs() } else { self.value },
+            negative: false,
+            ..*self
+        }
+    }
+
+    /// Return the greatest integer less than or equal to self, like Python's `math.floor()`.
+    pub fn floor(&self) -> Int {
+        self.check_finite();
+        self.value.floor()
+    }
+
+    /// Return the least integer greater than or equal to self, like Python's `math.ceil()`.
+    pub fn ceil(&self) -> Int {
+        self.check_finite();
+        self.value.ceil()
     }
 
-    // a is numerator, b is denominator, return (start_index, length)
-    fn find_cyclic(a: i128, b: i128) -> Option<(usize, usize)> {
-        let mut remainders = Vec::new();
-        let mut remainder = a % b;
+    /// Return the integer part of self, rounding toward zero, like Python's `math.trunc()`.
+    pub fn trunc(&self) -> Int {
+        self.check_finite();
+        self.value.trunc()
+    }
+
+    /// Return the nearest integer to self, rounding half to even, like Python's `round()`.
+    pub fn round(&self) -> Int {
+        self.check_finite();
+        self.value.round()
+    }
 
-        // check for repeating cycle
-        while remainder != 0 {
-            if remainders.contains(&remainder) {
-                // found a repeating cycle
-                let start = remainders.iter().position(|r| *r == remainder).unwrap();
-                let length = remainders.len() - start;
-                return Some((start, length));
+    /// Return self rounded to `ndigits` decimal places, rounding half to even, like Python's `round(x, ndigits)`.
+    /// `ndigits` can be negative.
+    pub fn round_to(&self, ndigits: i32) -> Self {
+        if !self.is_finite() {
+            return Self::propagate(&[self]).unwrap_or(*self);
+        }
+
+        match self.value.checked_round_with(ndigits, Rounding::HalfEven) {
+            Some(value) => Self::signed(value, self.negative).inexact(self),
+            None => Self::overflow(self.negative),
+        }
+    }
+
+    /// Return self rounded to `places` decimal places, like Python's `Decimal.quantize()` with the exponent `-places`.
+    /// A decimal keeps no trailing zeros, so the places are given explicitly, `places` can be negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pyinrs::{Decimal, Rounding};
+    /// assert_eq!(Decimal::from("2.675").quantize(2, Rounding::HalfUp), Decimal::from("2.68"));
+    /// assert_eq!(Decimal::from("2.665").quantize(2, Rounding::HalfEven), Decimal::from("2.66"));
+    /// ```
+    pub fn quantize(&self, places: i32, rounding: Rounding) -> Self {
+        if let Some(nan) = Self::propagate(&[self]) {
+            return nan;
+        }
+        if self.is_infinite() {
+            return Self::invalid("Error: Invalid operation.");
+        }
+
+        match self.value.checked_round_with(places, rounding) {
+            Some(value) => Self::signed(value, self.negative).inexact(self),
+            None => Self::invalid("Error: The result does not fit in a decimal."),
+        }
+    }
+
+    /// Return self multiplied by `10**n`, like Python's `Decimal.scaleb()`.
+    /// If the result does not fit, signal `Overflow` for a positive `n`, or round it to zero for a negative `n`.
+    pub fn scaleb(&self, n: i32) -> Self {
+        if !self.is_finite() {
+            return Self::propagate(&[self]).unwrap_or(*self);
+        }
+
+        // scale in steps that fit, the cancellation keeps the intermediate values small
+        let (mut value, mut rest) = (Some(self.value), n);
+        while rest != 0 && value.is_some_and(|v| v != Fraction::ZERO) {
+            let step = rest.clamp(-38, 38);
+            value = value.zip(Fraction::checked_pow(Fraction::from(10), step)).and_then(|(v, scale)| v.checked_mul(scale));
+            rest -= step;
+        }
+
+        match value {
+            Some(value) => Self::signed(value, self.negative),
+            None if n > 0 => Self::overflow(self.negative),
+            None => {
+                Context::signal_inexact();
+                Self::signed(Fraction::ZERO, self.negative)
             }
-            remainders.push(remainder);
-            remainder = (remainder * 10) % b; // left shift and continue iteration
         }
+    }
 
-        // no repeating cycle found
+    /// Return self rounded to the precision of the current context, like Python's `Decimal.normalize()`.
+    /// A decimal keeps no trailing zeros, so only the rounding takes effect.
+    /// If the digits do not fit in a fraction of `i128`, fewer digits are kept.
+    pub fn normalize(&self) -> Self {
+        if !self.is_finite() {
+            return Self::propagate(&[self]).unwrap_or(*self);
+        }
+
+        let context = Context::current();
+        self.round_significant(context.prec, context.rounding).inexact(self)
+    }
+
+    /// Return the square root correctly rounded to `digits` significant digits, like Python's `Decimal.sqrt()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pyinrs::Decimal;
+    /// assert_eq!(Decimal::from(2).sqrt(10), Decimal::from("1.414213562"));
+    /// assert_eq!(Decimal::from("0.~4").sqrt(5), Decimal::from("0.66667"));
+    /// ```
+    pub fn sqrt(&self, digits: usize) -> Self {
+        if let Some(nan) = Self::propagate(&[self]) {
+            return nan;
+        }
+        if self.is_zero() {
+            return *self;
+        }
+        if self.negative {
+            return Self::invalid("Error: Math domain error.");
+        }
+        if self.is_infinite() {
+            return *self;
+        }
+
+        if let Some(root) = Fraction::pow_rational(self.value, Fraction::from((1, 2))) {
+            return Self::from(root).round_significant(digits, Rounding::HalfEven).inexact(&Self::from(root));
+        }
+
+        let (num, den) = (Int::from(self.value.numerator()), Int::from(self.value.denominator()));
+        Self::ziv(digits, |guard| {
+            let w = (digits as i64 + guard - self.adjusted() as i64 / 2).max(0);
+            (Int::sqrt(&(&num * pow10(2 * w) / &den)), w, Int::from(1))
+        })
+    }
+
+    /// Return `e**self` rounded to `digits` significant digits, like Python's `Decimal.exp()`.
+    pub fn exp(&self, digits: usize) -> Self {
+        if let Some(nan) = Self::propagate(&[self]) {
+            return nan;
+        }
+        if self.is_infinite() {
+            return if self.negative { Self::new() } else { *self };
+        }
+        if self.is_zero() {
+            return Self::from(1);
+        }
+
+        let x = f64::from(*self);
+        if let Some(result) = Self::check_fit(x) {
+            return result;
+        }
+        Self::ziv(digits, |guard| {
+            let w = digits as i64 + guard + 2 + Self::tiny_digits(x);
+            let m = exp_fixed(&self.fixed(w), w);
+            let err = m.abs() / pow10(digits as i64 + guard) + Int::from(10);
+            (m, w, err)
+        })
+    }
+
+    /// Return the natural logarithm rounded to `digits` significant digits, like Python's `Decimal.ln()`.
+    pub fn ln(&self, digits: usize) -> Self {
+        if let Some(special) = self.log_special() {
+            return special;
+        }
+        if self.value == Fraction::from(1) {
+            return Self::new();
+        }
+
+        Self::ziv(digits, |guard| {
+            let w = digits as i64 + guard + 2 + self.near_one_digits();
+            (self.ln_fixed(w), w, Int::from(100))
+        })
+    }
+
+    /// Return the base 10 logarithm rounded to `digits` significant digits, like Python's `Decimal.log10()`.
+    pub fn log10(&self, digits: usize) -> Self {
+        if let Some(special) = self.log_special() {
+            return special;
+        }
+
+        // exact for powers of ten
+        let is_pow10 = |mut n: i128| {
+            while n % 10 == 0 {
+                n /= 10;
+            }
+            n == 1
+        };
+        let (num, den) = (self.value.numerator(), self.value.denominator());
+        if den == 1 && is_pow10(num) || num == 1 && is_pow10(den) {
+            return Self::from(self.adjusted());
+        }
+
+        Self::ziv(digits, |guard| {
+            let w = digits as i64 + guard + 2 + self.near_one_digits();
+            let m = self.ln_fixed(w) * pow10(w) / Self::from(10).ln_fixed(w);
+            (m, w, Int::from(100))
+        })
+    }
+
+    /// Return `base**exp` rounded to `digits` significant digits, `exp` can be non-integer, like Python's `Decimal.__pow__()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pyinrs::Decimal;
+    /// assert_eq!(Decimal::pow(Decimal::from(2), Decimal::from("0.5"), 8), Decimal::from("1.4142136"));
+    /// assert_eq!(Decimal::pow(Decimal::from(-8), Decimal::from("0.~3"), 8), Decimal::from(-2));
+    /// ```
+    pub fn pow(base: Self, exp: Self, digits: usize) -> Self {
+        if let Some(nan) = Self::propagate(&[&base, &exp]) {
+            return nan;
+        }
+        if base.is_zero() && exp.is_zero() {
+            return Self::invalid("Error: Invalid operation.");
+        }
+        if base == Self::NEG_INFINITY && !(exp.is_finite() && exp.value.denominator() == 1) {
+            return Self::invalid("Error: Math domain error.");
+        }
+        if base.is_infinite() || exp.is_infinite() {
+            // the results are exact in IEEE 754 like 0, 1 or Infinity
+            return Self::from(f64::from(base).powf(f64::from(exp)));
+        }
+
+        let (b, e) = (base.value, exp.value);
+        if e == Fraction::new() {
+            return Self::from(1);
+        }
+        if b == Fraction::new() {
+            if e < Fraction::new() {
+                Context::signal(Signal::DivisionByZero, "Error: Divide by zero.");
+                return Self::INFINITY;
+            }
+            return Self::new();
+        }
+
+        // exact when the base has a rational root of the denominator of the exponent
+        let root = Fraction::pow_rational(b, Fraction::from((1, e.denominator())));
+        if let Some(r) = root.and_then(|r| Fraction::checked_pow(r, i32::try_from(e.numerator()).ok()?)) {
+            return Self::from(r).round_significant(digits, Rounding::HalfEven).inexact(&Self::from(r));
+        }
+        if b < Fraction::new() && root.is_none() {
+            return Self::invalid("Error: Math domain error.");
+        }
+
+        // |base|**exp = exp(exp * ln|base|), negative if the base is negative and the root is odd
+        let negative = b < Fraction::new() && e.numerator() % 2 != 0;
+        let (b, e) = (base.abs(), exp);
+        let t = f64::from(e) * f64::from(b).ln();
+        let result = Self::check_fit(t).unwrap_or_else(|| {
+            Self::ziv(digits, |guard| {
+                let w = digits as i64 + guard + 2 + Self::tiny_digits(t);
+                let extra = e.trunc().digits() as i64 + 2;
+                let t = b.ln_fixed(w + extra) * Int::from(e.value.numerator()) / Int::from(e.value.denominator()) / pow10(extra);
+                let m = exp_fixed(&t, w);
+                let err = m.abs() / pow10(digits as i64 + guard) + Int::from(10);
+                (m, w, err)
+            })
+        });
+        if negative {
+            -result
+        } else {
+            result
+        }
+    }
+
+    /// Return the sine of self (in radians) rounded to `digits` significant digits.
+    pub fn sin(&self, digits: usize) -> Self {
+        if let Some(special) = self.trig_special() {
+            return special;
+        }
+
+        Self::ziv(digits, |guard| {
+            let w = digits as i64 + guard + 2;
+            (self.sin_cos_fixed(w).0, w, Int::from(10))
+        })
+    }
+
+    /// Return the cosine of self (in radians) rounded to `digits` significant digits.
+    pub fn cos(&self, digits: usize) -> Self {
+        if self.is_zero() {
+            return Self::from(1);
+        }
+        if let Some(special) = self.trig_special() {
+            return special;
+        }
+
+        Self::ziv(digits, |guard| {
+            let w = digits as i64 + guard + 2;
+            (self.sin_cos_fixed(w).1, w, Int::from(10))
+        })
+    }
+
+    /// Return the tangent of self (in radians) rounded to `digits` significant digits.
+    pub fn tan(&self, digits: usize) -> Self {
+        if let Some(special) = self.trig_special() {
+            return special;
+        }
+
+        Self::ziv(digits, |guard| {
+            let w = digits as i64 + guard + 2;
+            let (sin, cos) = self.sin_cos_fixed(w);
+            if cos.abs() <= Int::from(100) {
+                // too close to a pole, retry with more digits
+                return (Int::new(), w, Int::from(1));
+            }
+            let s = pow10(w);
+            let m = &sin * &s / &cos;
+            let err = (m.abs() + s) * Int::from(10) / cos.abs() + Int::from(1);
+            (m, w, err)
+        })
+    }
+
+    /// Return the arc tangent of self (in radians) rounded to `digits` significant digits.
+    pub fn atan(&self, digits: usize) -> Self {
+        if let Some(nan) = Self::propagate(&[self]) {
+            return nan;
+        }
+        if self.is_zero() {
+            return *self;
+        }
+        if self.is_infinite() {
+            // +-pi/2
+            let result = Self::ziv(digits, |guard| {
+                let w = digits as i64 + guard + 2;
+                (pi(w) / Int::from(2), w, Int::from(1))
+            });
+            return if self.negative { -result } else { result };
+        }
+
+        let tiny = (-f64::from(*self).abs().log10().floor()).max(0.0) as i64;
+        Self::ziv(digits, |guard| {
+            let w = digits as i64 + guard + 2 + tiny;
+            (self.atan_fixed(w), w, Int::from(100))
+        })
+    }
+
+    // The result of logarithms for special values, zero and negative values.
+    fn log_special(&self) -> Option<Self> {
+        if let Some(nan) = Self::propagate(&[self]) {
+            return Some(nan);
+        }
+        if self.is_zero() {
+            return Some(Self::NEG_INFINITY);
+        }
+        if self.negative {
+            return Some(Self::invalid("Error: Math domain error."));
+        }
+        self.is_infinite().then_some(*self)
+    }
+
+    // The result of sine and tangent for special values and zero, NaN for infinity.
+    fn trig_special(&self) -> Option<Self> {
+        if self.is_infinite() {
+            return Some(Self::invalid("Error: Math domain error."));
+        }
+        Self::propagate(&[self]).or(self.is_zero().then_some(*self))
+    }
+
+    // Evaluate `f(guard)`, which returns `(x * 10**w, w, error bound)`, with more and more guard digits
+    // until the interval rounds to `digits` significant digits unambiguously, rounding half to even.
+    // The result is correctly rounded only if the error bound holds, which is proven for `sqrt()` alone,
+    // the bounds of the series are generous estimates.
+    fn ziv(digits: usize, f: impl Fn(i64) -> (Int, i64, Int)) -> Self {
+        if digits == 0 {
+            panic!("Error: Require digits >= 1.");
+        }
+
+        // the results of these functions are irrational
+        Context::signal_inexact();
+
+        let mut guard = 10;
+        loop {
+            let (m, w, err) = f(guard);
+            let lo = Self::round_fixed(&(&m - &err), w, digits);
+            let hi = Self::round_fixed(&(&m + &err), w, digits);
+            match (lo, hi) {
+                (Some(lo), Some(hi)) if lo == hi => return Self::from_rounded(lo),
+                // a tie that never resolves is impossible for these functions, give up at a huge precision anyway
+                _ if guard > 2000 => return Self::from_rounded(Self::round_fixed(&m, w, digits).unwrap()),
+                _ => guard *= 2,
+            }
+        }
+    }
+
+    // Round `v * 10**-w` to `digits` significant digits, return `(negative, coefficient, exponent)`.
+    // Return `None` if there are not enough digits to decide.
+    fn round_fixed(v: &Int, w: i64, digits: usize) -> Option<(bool, Int, i64)> {
+        if v.digits() < digits + 2 {
+            return None;
+        }
+
+        let drop = (v.digits() - digits) as i64;
+        let unit = pow10(drop);
+        let (mut q, r) = v.abs().divmod(&unit);
+        let half = (&r + &r).cmp(&unit);
+        if half.is_gt() || half.is_eq() && q.is_odd() {
+            q += &Int::from(1);
+        }
+        if q.digits() > digits {
+            q /= Int::from(10);
+            return Some((v.is_negative(), q, drop + 1 - w));
+        }
+        Some((v.is_negative(), q, drop - w))
+    }
+
+    // Construct from `(negative, coefficient, exponent)`, signal `Overflow` if it is too large.
+    // Fewer digits are kept if they do not fit, so a tiny value may be rounded to zero.
+    fn from_rounded((negative, mut q, mut exp): (bool, Int, i64)) -> Self {
+        // 10**38 is the largest power of ten in i128
+        while exp < 0 && (exp < -38 || Fraction::try_from(&q).is_err()) {
+            let drop = (-38 - exp).clamp(1, -exp);
+            let unit = pow10(drop);
+            let (t, r) = q.divmod(&unit);
+            let half = (&r + &r).cmp(&unit);
+            q = if half.is_gt() || half.is_eq() && t.is_odd() { t + Int::from(1) } else { t };
+            exp += drop;
+        }
+
+        let value = match u32::try_from(-exp) {
+            Ok(e) => Fraction::try_from(q).ok().map(|q| Fraction::from((q.numerator(), 10i128.pow(e)))),
+            Err(_) => Fraction::try_from(q * pow10(exp)).ok(),
+        };
+        match value {
+            Some(value) => Self::signed(if negative { -value } else { value }, negative),
+            None => Self::overflow(negative),
+        }
+    }
+
+    // The result if `exp(x)` can not be a decimal, signal `Overflow` if it is too large, or round it to zero if it is too small.
+    fn check_fit(x: f64) -> Option<Self> {
+        if x > 100.0 {
+            Context::signal(Signal::Overflow, "Error: The result does not fit in a decimal.");
+            return Some(Self::INFINITY);
+        }
+        if x < -100.0 {
+            Context::signal_inexact();
+            return Some(Self::new());
+        }
         None
     }
+
+    // More digits needed for the tiny result of `exp(x)`.
+    fn tiny_digits(x: f64) -> i64 {
+        (-x * std::f64::consts::LOG10_E).max(0.0).ceil() as i64
+    }
+
+    // More digits needed for the tiny result of `ln(self)` when self is near one.
+    fn near_one_digits(&self) -> i64 {
+        let near = f64::from(self.value - Fraction::from(1)).abs();
+        (-near.log10().floor()).max(0.0) as i64
+    }
+
+    // self * 10**w truncated, `w >= 0`.
+    fn fixed(&self, w: i64) -> Int {
+        Int::from(self.value.numerator()) * pow10(w) / Int::from(self.value.denominator())
+    }
+
+    // ln(self) * 10**w for self > 0, with an error of a few units.
+    fn ln_fixed(&self, w: i64) -> Int {
+        // self = u * 2**a with u near 1, and ln(u) = 2 atanh((u - 1) / (u + 1))
+        let a = f64::from(*self).log2().round() as i64;
+        let (mut num, mut den) = (Int::from(self.value.numerator()), Int::from(self.value.denominator()));
+        let shift = Int::pow(&Int::from(2), &Int::from(a.abs()));
+        if a > 0 {
+            den *= &shift;
+        } else {
+            num *= &shift;
+        }
+
+        let s = pow10(w + 2);
+        let z = (&num - &den) * &s / (num + den);
+        (atan_series(&z, &s, true) * Int::from(2) + Int::from(a) * ln2(w + 2)) / Int::from(100)
+    }
+
+    // (sin(self) * 10**w, cos(self) * 10**w), with an error of a few units.
+    fn sin_cos_fixed(&self, w: i64) -> (Int, Int) {
+        // self = q * pi/2 + y, the reduction needs as many more digits as the quotient has
+        let extra = self.trunc().digits() as i64 + 3;
+        let s = pow10(w + extra);
+        let x = self.fixed(w + extra);
+        let half_pi = pi(w + extra) / Int::from(2);
+        let q = &x / &half_pi;
+        let (sin, cos) = sin_cos_series(&(x - &q * half_pi), &s);
+
+        let quadrant = ((q % Int::from(4) + Int::from(4)) % Int::from(4)).to_number::<i64>();
+        let (sin, cos) = match quadrant {
+            0 => (sin, cos),
+            1 => (cos, -sin),
+            2 => (-sin, -cos),
+            _ => (-cos, sin),
+        };
+        (sin / pow10(extra), cos / pow10(extra))
+    }
+
+    // atan(self) * 10**w, with an error of a few units.
+    fn atan_fixed(&self, w: i64) -> Int {
+        // atan(x) = pi/2 - atan(1/x) for x > 1
+        let big = self.value.abs() > Fraction::from(1);
+        let x = if big { Self::from(Fraction::from(1) / self.value.abs()) } else { self.abs() };
+
+        // atan(x) = 2 atan(x / (1 + sqrt(1 + x**2))), twice to make x <= tan(pi/16)
+        let s = pow10(w + 2);
+        let mut x = x.fixed(w + 2);
+        for _ in 0..2 {
+            x = &x * &s / (&s + Int::sqrt(&(&s * &s + &x * &x)));
+        }
+
+        let mut r = atan_series(&x, &s, false) * Int::from(4);
+        if big {
+            r = pi(w + 2) / Int::from(2) - r;
+        }
+        if self.value < Fraction::new() {
+            r = -r;
+        }
+        r / Int::from(100)
+    }
+
+    // The adjusted exponent of a nonzero value, 10**adjusted <= |self| < 10**(adjusted + 1).
+    fn adjusted(&self) -> i32 {
+        let (num, den) = (Int::from(self.value.numerator()).abs(), Int::from(self.value.denominator()));
+        let adjusted = num.digits() as i32 - den.digits() as i32;
+        let shift = pow10(adjusted.abs() as i64);
+        let too_big = if adjusted >= 0 { &den * shift > num } else { den > &num * shift };
+        adjusted - too_big as i32
+    }
+
+    // Round to `digits` significant digits.
+    fn round_significant(&self, digits: usize, rounding: Rounding) -> Self {
+        if self.value == Fraction::new() {
+            return *self;
+        }
+
+        let ndigits = digits as i32 - 1 - self.adjusted();
+        if self.places().is_some_and(|places| places as i32 <= ndigits) {
+            return *self;
+        }
+
+        // keep fewer decimal places if they do not fit, 10**38 is the largest power of ten in i128
+        let mut ndigits = ndigits.min(38);
+        loop {
+            match self.value.checked_round_with(ndigits, rounding) {
+                Some(value) => return Self::signed(value, self.negative),
+                None if ndigits > 0 => ndigits -= 1,
+                None => return Self::overflow(self.negative),
+            }
+        }
+    }
+
+    // Count the decimal places if self terminates.
+    fn places(&self) -> Option<u32> {
+        let den = self.value.denominator();
+        let twos = den.trailing_zeros();
+        let (mut rest, mut fives) = (den >> twos, 0);
+        while rest % 5 == 0 {
+            rest /= 5;
+            fives += 1;
+        }
+        (rest == 1).then_some(twos.max(fives))
+    }
+
+    /// Return the exact decimal expansion, writing the repeating part in the specified notation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pyinrs::{CyclicNotation, Decimal};
+    /// let d = Decimal::from(1) / Decimal::from(6);
+    /// assert_eq!(d.to_cyclic_string(CyclicNotation::Tilde), "0.1~6");
+    /// assert_eq!(d.to_cyclic_string(CyclicNotation::Parentheses), "0.1(6)");
+    /// assert_eq!(d.to_cyclic_string(CyclicNotation::Overline), "0.16\u{305}");
+    /// ```
+    pub fn to_cyclic_string(&self, notation: CyclicNotation) -> String {
+        self.expand_string(10, notation)
+    }
+
+    /// Return the exact expansion in `radix` with the repeating part detected in that radix, like `-1A.~3#16`.
+    /// The output parses back to the same value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pyinrs::Decimal;
+    /// assert_eq!(Decimal::from("0.1").to_string_radix(2), "0.0~0011#2");
+    /// assert_eq!(Decimal::from("-255.5").to_string_radix(16), "-FF.8#16");
+    /// ```
+    pub fn to_string_radix(&self, radix: u32) -> String {
+        if !(2..=36).contains(&radix) {
+            panic!("Error: Require 2 <= radix <= 36 for to_string_radix().");
+        }
+
+        if !self.is_finite() {
+            return self.to_string();
+        }
+        format!("{}#{radix}", self.expand_string(radix, CyclicNotation::Tilde))
+    }
+
+    /// Return the string with an exponent if needed, like Python's `Decimal.to_sci_string()`.
+    /// A decimal keeps no trailing zeros, so the exponent is that of the reduced form,
+    /// and a repeating decimal is rounded to the precision of the current context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pyinrs::Decimal;
+    /// assert_eq!(Decimal::from(1234500).to_sci_string(), "1.2345E+6");
+    /// assert_eq!(Decimal::from("123.45").to_sci_string(), "123.45");
+    /// assert_eq!(Decimal::from("0.00000012").to_sci_string(), "1.2E-7");
+    /// ```
+    pub fn to_sci_string(&self) -> String {
+        self.exponent_string(false)
+    }
+
+    /// Return the string with an exponent of a multiple of three if needed, like Python's `Decimal.to_eng_string()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pyinrs::Decimal;
+    /// assert_eq!(Decimal::from(12345000).to_eng_string(), "12.345E+6");
+    /// assert_eq!(Decimal::from("0.00000012").to_eng_string(), "120E-9");
+    /// ```
+    pub fn to_eng_string(&self) -> String {
+        self.exponent_string(true)
+    }
+
+    // Python's `Decimal.__str__()` in scientific or engineering notation.
+    fn exponent_string(&self, eng: bool) -> String {
+        if !self.is_finite() {
+            return self.expand_string(10, CyclicNotation::Tilde);
+        }
+
+        let (coef, exp) = self.coefficient();
+        let leftdigits = exp + coef.len() as i32;
+        let dotplace = if exp <= 0 && leftdigits > -6 {
+            leftdigits
+        } else if !eng {
+            1
+        } else if coef == "0" {
+            (leftdigits + 1).rem_euclid(3) - 1
+        } else {
+            (leftdigits - 1).rem_euclid(3) + 1
+        };
+
+        let sign = if self.negative { "-" } else { "" };
+        format!("{sign}{}", Self::place_point(&coef, dotplace, leftdigits - dotplace, 'E', false))
+    }
+
+    // Write the body with the sign, padded to the width, infinity and NaN are never padded with zeros.
+    fn pad(&self, f: &mut std::fmt::Formatter, body: &str) -> std::fmt::Result {
+        if self.is_finite() {
+            return f.pad_integral(!self.negative, "", body);
+        }
+
+        let sign = if self.negative {
+            "-"
+        } else if f.sign_plus() {
+            "+"
+        } else {
+            ""
+        };
+        let s = format!("{sign}{body}");
+        let pad = f.width().unwrap_or(0).saturating_sub(s.chars().count());
+        let (left, right) = match f.align() {
+            Some(Alignment::Left) => (0, pad),
+            Some(Alignment::Center) => (pad / 2, pad - pad / 2),
+            _ => (pad, 0),
+        };
+        let fill = |n: usize| f.fill().to_string().repeat(n);
+        write!(f, "{}{s}{}", fill(left), fill(right))
+    }
+
+    // Python's `Decimal.__format__()` in the presentation type `e`, `E`, `f` or `g` without the sign.
+    fn format_body(&self, kind: char, precision: Option<usize>) -> String {
+        if !self.is_finite() {
+            return self.abs().expand_string(10, CyclicNotation::Tilde);
+        }
+
+        // round to the precision, then pad zeros to it like Python's `_rescale()`
+        let rounding = Context::current().rounding;
+        let significant = match (kind, precision) {
+            ('e' | 'E', Some(p)) => Some(p + 1),
+            ('g', Some(p)) if self.coefficient().0.len() > p => Some(p.max(1)),
+            _ => None,
+        };
+        let (mut coef, mut exp) = match (significant, precision) {
+            (Some(digits), _) if !self.is_zero() => self.digits_at(digits as i32 - 1 - self.adjusted(), rounding),
+            (None, Some(p)) if kind == 'f' => self.digits_at(p as i32, rounding),
+            _ => self.coefficient(),
+        };
+        let target = match (significant, precision) {
+            _ if coef == "0" && kind != 'f' => exp,
+            (Some(digits), _) => exp + coef.len() as i32 - digits as i32,
+            (None, Some(p)) if kind == 'f' => -(p as i32),
+            _ => exp,
+        };
+        if target < exp {
+            if coef != "0" {
+                coef += &"0".repeat((exp - target) as usize);
+            }
+            exp = target;
+        }
+
+        let leftdigits = exp + coef.len() as i32;
+        let dotplace = match kind {
+            'e' | 'E' if coef == "0" => 1 - precision.unwrap_or(0) as i32,
+            'e' | 'E' => 1,
+            'f' => leftdigits,
+            _ if exp <= 0 && leftdigits > -6 => leftdigits,
+            _ => 1,
+        };
+        let echar = if kind == 'E' { 'E' } else { 'e' };
+        let exp = leftdigits - dotplace;
+        Self::place_point(&coef, dotplace, exp, echar, matches!(kind, 'e' | 'E'))
+    }
+
+    // The digits of the reduced coefficient and the exponent, a repeating decimal is rounded to the current context.
+    fn coefficient(&self) -> (String, i32) {
+        if self.places().is_none() {
+            let context = Context::current();
+            return self.digits_at(context.prec as i32 - 1 - self.adjusted(), context.rounding);
+        }
+
+        let (integral, decimal, _) = self.expand(10);
+        let digits = integral + &decimal;
+        let digits = digits.trim_start_matches('0');
+        let coef = digits.trim_end_matches('0');
+        if coef.is_empty() {
+            return ("0".to_string(), 0);
+        }
+        (coef.to_string(), (digits.len() - coef.len()) as i32 - decimal.len() as i32)
+    }
+
+    // The digits of the reduced coefficient and the exponent after rounding to `places` decimal places, `places` can be negative.
+    // The digits are computed with big integers, so any precision fits.
+    fn digits_at(&self, places: i32, rounding: Rounding) -> (String, i32) {
+        let (num, den) = (Int::from(self.value.numerator()).abs(), Int::from(self.value.denominator()));
+        let (num, den) = if places >= 0 {
+            (num * pow10(places as i64), den)
+        } else {
+            (num, den * pow10(-places as i64))
+        };
+        let (mut q, r) = num.divmod(&den);
+        let last = (&q % Int::from(10)).to_number::<i64>() as u8;
+        if rounding.round_up(self.value < Fraction::ZERO, last, (&r + &r).cmp(&den), !r.is_zero()) {
+            q += &Int::from(1);
+        }
+
+        let digits = q.to_string();
+        let coef = digits.trim_end_matches('0');
+        if coef.is_empty() {
+            return ("0".to_string(), 0);
+        }
+        (coef.to_string(), (digits.len() - coef.len()) as i32 - places)
+    }
+
+    // Place the decimal point in the coefficient, followed by the exponent if it is not zero or `force_exp`.
+    fn place_point(coef: &str, dotplace: i32, exp: i32, echar: char, force_exp: bool) -> String {
+        let (integral, fraction) = if dotplace <= 0 {
+            ("0".to_string(), "0".repeat(-dotplace as usize) + coef)
+        } else if dotplace as usize >= coef.len() {
+            (coef.to_string() + &"0".repeat(dotplace as usize - coef.len()), String::new())
+        } else {
+            (coef[..dotplace as usize].to_string(), coef[dotplace as usize..].to_string())
+        };
+
+        let mut s = integral;
+        if !fraction.is_empty() {
+            s += &format!(".{fraction}");
+        }
+        if exp != 0 || force_exp {
+            s += &format!("{echar}{exp:+}");
+        }
+        s
+    }
+
+    // Write the expansion in `radix` with the sign, the repeating part in the notation.
+    fn expand_string(&self, radix: u32, notation: CyclicNotation) -> String {
+        let sign = if self.negative { "-" } else { "" };
+        let payload = |name: &str| match self.value.numerator() {
+            0 => format!("{sign}{name}"),
+            payload => format!("{sign}{name}{payload}"),
+        };
+        match self.kind {
+            Kind::Finite => {}
+            Kind::Infinite => return format!("{sign}Infinity"),
+            Kind::QNaN => return payload("NaN"),
+            Kind::SNaN => return payload("sNaN"),
+        }
+
+        let (integral, decimal, cyclic) = self.expand(radix);
+        let mut s = format!("{sign}{integral}");
+        if !decimal.is_empty() || !cyclic.is_empty() {
+            s += ".";
+            s += &decimal;
+        }
+        if !cyclic.is_empty() {
+            match notation {
+                CyclicNotation::Tilde => s += &format!("~{cyclic}"),
+                CyclicNotation::Parentheses => s += &format!("({cyclic})"),
+                CyclicNotation::Overline => s.extend(cyclic.chars().flat_map(|c| [c, '\u{305}'])),
+            }
+        }
+        s
+    }
+
+    // Expand the absolute value in `radix` by long division, return the digits of (integral, decimal, cyclic) parts.
+    fn expand(&self, radix: u32) -> (String, String, String) {
+        let digit = |d: u128| char::from_digit(d as u32, radix).unwrap().to_ascii_uppercase();
+        let (num, den) = (self.value.numerator().unsigned_abs(), self.value.denominator() as u128);
+
+        let mut integral = String::new();
+        let mut n = num / den;
+        loop {
+            integral.insert(0, digit(n % radix as u128));
+            n /= radix as u128;
+            if n == 0 {
+                break;
+            }
+        }
+
+        // (r * radix) divmod den, without overflow since r < den <= i128::MAX
+        let next = |r: u128| {
+            let (mut q, mut acc) = (0, 0);
+            for _ in 0..radix {
+                acc += r;
+                if acc >= den {
+                    acc -= den;
+                    q += 1;
+                }
+            }
+            (q, acc)
+        };
+
+        // the length of non-repeating part is the number of times to remove common factors of den and radix
+        let (mut rest, mut pre) = (den, 0);
+        loop {
+            let gcd = detail::gcd(rest, radix as u128);
+            if gcd == 1 {
+                break;
+            }
+            rest /= gcd;
+            pre += 1;
+        }
+
+        let mut r = num % den;
+        let mut decimal = String::new();
+        for _ in 0..pre {
+            if r == 0 {
+                break;
+            }
+            let (q, next_r) = next(r);
+            decimal.push(digit(q));
+            r = next_r;
+        }
+
+        // now the remainders repeat from r, since rest is coprime to radix
+        let mut cyclic = String::new();
+        if r != 0 {
+            let start = r;
+            loop {
+                let (q, next_r) = next(r);
+                cyclic.push(digit(q));
+                r = next_r;
+                if r == start {
+                    break;
+                }
+            }
+        }
+
+        (integral, decimal, cyclic)
+    }
+
+    // Rewrite the repeating part written as `0.(3)` or `0.3̅` into `0.~3`.
+    fn normalize_cyclic(s: &str) -> String {
+        let (body, radix) = match s.split_once('#') {
+            Some((body, radix)) => (body, format!("#{radix}")),
+            None => (s, String::new()),
+        };
+
+        if let (Some(body), Some(open)) = (body.strip_suffix(')'), body.find('(')) {
+            return format!("{}~{}{radix}", &body[..open], &body[open + 1..]);
+        }
+
+        if let Some(first) = body.find('\u{305}') {
+            // the overlined digits must be a suffix, otherwise keep the overlines to fail the parsing
+            let (head, tail) = body.split_at(first);
+            let mut head = head.chars();
+            let last = head.next_back().unwrap_or_default();
+            let tail = format!("{last}{tail}");
+            let cyclic: Option<String> = tail
+                .chars()
+                .collect::<Vec<_>>()
+                .chunks(2)
+                .map(|p| (p.len() == 2 && p[1] == '\u{305}').then_some(p[0]))
+                .collect();
+            if let Some(cyclic) = cyclic {
+                return format!("{}~{cyclic}{radix}", head.as_str());
+            }
+        }
+
+        s.to_string()
+    }
+}
+
+/// Notation of the repeating part of a decimal, e.g. 1/3 is written as `0.~3`, `0.(3)` or `0.3̅`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CyclicNotation {
+    /// `0.~3`, the same as the input format, this is the default.
+    #[default]
+    Tilde,
+
+    /// `0.(3)`
+    Parentheses,
+
+    /// `0.3̅`, using the combining overline `U+0305`.
+    Overline,
 }
 
 /*
@@ -75,63 +1163,196 @@ impl_from_integer!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
 
 impl From<f64> for Decimal {
     fn from(value: f64) -> Self {
-        Self::from(Fraction::from(value))
+        if value.is_nan() {
+            Self::special(Fraction::ZERO, Kind::QNaN, value.is_sign_negative())
+        } else if value.is_infinite() {
+            Self::special(Fraction::ZERO, Kind::Infinite, value.is_sign_negative())
+        } else {
+            Self::signed(Fraction::from(value), value.is_sign_negative())
+        }
     }
 }
 
 impl From<f32> for Decimal {
     fn from(value: f32) -> Self {
-        Self::from(Fraction::from(value))
+        Self::from(value as f64)
     }
 }
 
 impl From<Fraction> for Decimal {
     fn from(value: Fraction) -> Self {
-        Self { value }
+        Self::signed(value, false)
+    }
+}
+
+impl TryFrom<&Int> for Decimal {
+    type Error = ConversionError;
+
+    fn try_from(value: &Int) -> Result<Self, Self::Error> {
+        Fraction::try_from(value).map(Self::from)
+    }
+}
+
+impl TryFrom<Int> for Decimal {
+    type Error = ConversionError;
+
+    fn try_from(value: Int) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
     }
 }
 
 impl From<&str> for Decimal {
     fn from(value: &str) -> Self {
-        Self::from_str(value).unwrap_or_else(|_| panic!("expect format: `[+-]integer[.decimal][~cyclic][#radix]` but got `{}`", value))
+        Self::from_str(value).unwrap_or_else(|_| panic!("expect format: `[+-]integer[.decimal][~cyclic][e[+-]exponent][#radix]` but got `{}`", value))
     }
 }
 
+/// An error which can be returned when parsing a decimal.
 #[derive(Debug, PartialEq, Eq)]
-pub struct ParseDecimalError;
+pub enum ParseDecimalError {
+    /// The string is empty.
+    Empty,
+
+    /// The string contains a digit invalid for the radix or is not in a supported format.
+    InvalidDigit,
+
+    /// The radix is not in the range from 2 to 36.
+    InvalidRadix,
+
+    /// The exponent is missing or malformed.
+    InvalidExponent,
+
+    /// The value does not fit in a fraction of `i128`.
+    /// The digits are reduced as a whole first, so a long repeating part like `0.~111111` is not an overflow by itself.
+    Overflow,
+}
+
+impl Decimal {
+    // Remove the `_` separators, which are only allowed between digits.
+    fn strip_separators(s: &str) -> Result<String, ParseDecimalError> {
+        if s.starts_with('_') || s.ends_with('_') || s.contains("__") {
+            return Err(ParseDecimalError::InvalidDigit);
+        }
+        Ok(s.replace('_', ""))
+    }
+
+    // Parse the digits in the radix into a big integer.
+    fn parse_digits(s: &str, radix: u32) -> Result<Int, ParseDecimalError> {
+        let radix_int = Int::from(radix);
+        s.chars().try_fold(Int::new(), |value, c| {
+            let digit = c.to_digit(radix).ok_or(ParseDecimalError::InvalidDigit)?;
+            Ok(value * &radix_int + Int::from(digit))
+        })
+    }
+
+    // Parse a special value literal of Python's `Decimal`, like `Infinity`, `NaN` or `sNaN` with an optional payload.
+    fn parse_special(s: &str, negative: bool) -> Option<Result<Self, ParseDecimalError>> {
+        let s = s.to_ascii_lowercase();
+        if s == "inf" || s == "infinity" {
+            return Some(Ok(Self::special(Fraction::ZERO, Kind::Infinite, negative)));
+        }
+
+        let (kind, payload) = match s.strip_prefix("nan") {
+            Some(payload) => (Kind::QNaN, payload),
+            None => (Kind::SNaN, s.strip_prefix("snan")?),
+        };
+        if !payload.bytes().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        let payload = if payload.is_empty() { Ok(0) } else { payload.parse::<i128>() };
+        Some(
+            payload
+                .map(|payload| Self::special(Fraction::from(payload), kind, negative))
+                .map_err(|_| ParseDecimalError::Overflow),
+        )
+    }
+}
 
 impl FromStr for Decimal {
     type Err = ParseDecimalError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.trim();
-        let re_dec = Regex::new(r"^([-+])?([0-9A-Za-z]+)\.?([0-9A-Za-z]+)?~?([0-9A-Za-z]+)?#?(\d+)?$").unwrap();
-        let caps = re_dec.captures(s).ok_or(ParseDecimalError)?;
+        let s = &Self::normalize_cyclic(s.trim());
+        if s.is_empty() {
+            return Err(ParseDecimalError::Empty);
+        }
 
-        let sign = caps.get(1).map_or("+", |m| m.as_str());
-        let radix = caps.get(5).map_or(10, |m| m.as_str().parse::<u32>().unwrap());
-        let integral = i128::from_str_radix(&caps[2], radix).unwrap();
-        let decimal = caps.get(3).map(|x| i128::from_str_radix(x.as_str(), radix).unwrap());
-        let cyclic = caps.get(4).map(|x| i128::from_str_radix(x.as_str(), radix).unwrap());
+        let (negative, body) = match s.as_bytes()[0] {
+            b'-' => (true, &s[1..]),
+            b'+' => (false, &s[1..]),
+            _ => (false, s.as_str()),
+        };
+        if let Some(special) = Self::parse_special(body, negative) {
+            return special;
+        }
 
-        // x = c/((base^len(c)-1)*(base^len(d))) = c/(base^len(c+d)-base^len(d))
-        let decimal_len = caps.get(3).map_or(0, |m| m.len()) as u32;
-        let cyclic_len = caps.get(4).map_or(0, |m| m.len()) as u32;
-        let scale = i128::pow(radix as i128, decimal_len);
-        let repeat = i128::pow(radix as i128, decimal_len + cyclic_len) - scale;
+        let (body, radix) = match body.split_once('#') {
+            Some((body, radix)) => (
+                body,
+                radix.parse::<u32>().ok().filter(|r| (2..=36).contains(r)).ok_or(ParseDecimalError::InvalidRadix)?,
+            ),
+            None => (body, 10),
+        };
 
-        let value = match (integral, decimal, cyclic) {
-            // integer only
-            (i, None, None) => Fraction::from(i),
-            // integer + decimal
-            (i, Some(d), None) => Fraction::from((i * scale + d, scale)),
-            // integer + cyclic
-            (i, None, Some(c)) => Fraction::from(i) + Fraction::from((c, repeat)),
-            // integer + decimal + cyclic
-            (i, Some(d), Some(c)) => Fraction::from((i * scale + d, scale)) + Fraction::from((c, repeat)),
+        // `e` is a digit in radixes greater than 14, so exponents are only for radix 10
+        let (body, exp) = match body.find(['e', 'E']).filter(|_| radix == 10) {
+            Some(pos) => {
+                let exp = &body[pos + 1..];
+                let digits = exp.strip_prefix(['+', '-']).unwrap_or(exp);
+                if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_digit()) {
+                    return Err(ParseDecimalError::InvalidExponent);
+                }
+                (&body[..pos], exp.parse::<i32>().map_err(|_| ParseDecimalError::Overflow)?)
+            }
+            None => (body, 0),
         };
 
-        Ok(if sign == "-" { -Self { value } } else { Self { value } })
+        let (number, cyclic) = match body.split_once('~') {
+            Some((number, cyclic)) => (number, Some(cyclic)),
+            None => (body, None),
+        };
+        let (integral, decimal) = number.split_once('.').unwrap_or((number, ""));
+        let (integral, mut decimal) = (Self::strip_separators(integral)?, Self::strip_separators(decimal)?);
+        let cyclic = cyclic.map(Self::strip_separators).transpose()?;
+        if integral.is_empty() && decimal.is_empty() || cyclic.as_ref().is_some_and(|c| c.is_empty()) {
+            return Err(ParseDecimalError::InvalidDigit);
+        }
+
+        // trailing zeros of a terminating decimal are insignificant
+        if cyclic.is_none() {
+            decimal.truncate(decimal.trim_end_matches('0').len());
+        }
+
+        let i = Self::parse_digits(&integral, radix)?;
+        let d = Self::parse_digits(&decimal, radix)?;
+        let c = cyclic.as_deref().map(|c| Self::parse_digits(c, radix)).transpose()?;
+
+        // x = i + d/r^m + c/(r^m * (r^n - 1)), where m = len(d), n = len(c)
+        // computed with big integers, so only the reduced value has to fit in i128
+        let pow = |len: usize| Int::pow(&Int::from(radix), &Int::from(len));
+        let mut den = pow(decimal.len());
+        let mut num = i * &den + d;
+        if let (Some(c), Some(cyclic)) = (c, &cyclic) {
+            let repeat = pow(cyclic.len()) - Int::from(1);
+            num = num * &repeat + c;
+            den *= &repeat;
+        }
+        let gcd = Int::gcd(&num, &den);
+        let (num, den) = (Fraction::try_from(num / &gcd), Fraction::try_from(den / &gcd));
+        let mut num = num.map_err(|_| ParseDecimalError::Overflow)?.numerator();
+        let mut den = den.map_err(|_| ParseDecimalError::Overflow)?.numerator();
+
+        // scale by 10^exp, reducing before multiplying to avoid needless overflow
+        if num != 0 && exp != 0 {
+            let shift = 10i128.checked_pow(exp.unsigned_abs()).ok_or(ParseDecimalError::Overflow)?;
+            let (a, b) = if exp > 0 { (&mut num, &mut den) } else { (&mut den, &mut num) };
+            let gcd = detail::gcd(b.abs(), shift);
+            *b /= gcd;
+            *a = a.checked_mul(shift / gcd).ok_or(ParseDecimalError::Overflow)?;
+        }
+
+        let value = Fraction::from((num, den));
+        Ok(Self::signed(if negative { -value } else { value }, negative))
     }
 }
 
@@ -139,6 +1360,31 @@ impl FromStr for Decimal {
 Function
 */
 
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        // fractions are reduced, so comparing fields avoids overflow of cross multiplication
+        match (self.kind, other.kind) {
+            (Kind::Finite, Kind::Finite) => self.value == other.value,
+            (Kind::Infinite, Kind::Infinite) => self.negative == other.negative,
+            (Kind::QNaN, Kind::QNaN) | (Kind::SNaN, Kind::SNaN) => self.negative == other.negative && self.value == other.value,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Decimal {}
+
+impl Hash for Decimal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // -0 == 0, so the sign of zero must not be hashed
+        self.kind.hash(state);
+        if !self.is_zero() {
+            self.negative.hash(state);
+        }
+        self.value.hash(state);
+    }
+}
+
 impl PartialOrd for Decimal {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
@@ -146,8 +1392,42 @@ impl PartialOrd for Decimal {
 }
 
 impl Ord for Decimal {
+    /// Numbers are ordered by value, so -0 equals 0.
+    /// The NaNs are ordered like [`Decimal::compare_total()`], below `-Infinity` if negative, or above `Infinity` otherwise.
     fn cmp(&self, other: &Self) -> Ordering {
-        self.value.cmp(&other.value)
+        if self.is_nan() || other.is_nan() {
+            return self.compare_total(other);
+        }
+
+        // -Infinity < finite < Infinity
+        let rank = |x: &Self| match (x.kind, x.negative) {
+            (Kind::Infinite, true) => -1,
+            (Kind::Infinite, false) => 1,
+            _ => 0,
+        };
+        rank(self).cmp(&rank(other)).then_with(|| self.value.cmp(&other.value))
+    }
+}
+
+impl Decimal {
+    /// Compare using the abstract representation, which is a total order, like Python's `Decimal.compare_total()`.
+    ///
+    /// The order is -NaN < -sNaN < -Infinity < negative < -0 < 0 < positive < Infinity < sNaN < NaN,
+    /// NaNs with the same sign are ordered by payload.
+    pub fn compare_total(&self, other: &Self) -> Ordering {
+        let magnitude = |x: &Self| match x.kind {
+            Kind::Finite => (0, x.value.abs()),
+            Kind::Infinite => (1, x.value),
+            Kind::SNaN => (2, x.value),
+            Kind::QNaN => (3, x.value),
+        };
+
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => magnitude(self).cmp(&magnitude(other)),
+            (true, true) => magnitude(other).cmp(&magnitude(self)),
+        }
     }
 }
 
@@ -155,7 +1435,11 @@ impl Neg for Decimal {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
-        Self::from(-self.value)
+        Self {
+            value: if self.is_finite() { -self.value } else { self.value },
+            negative: !self.negative,
+            ..self
+        }
     }
 }
 
@@ -164,7 +1448,19 @@ impl Add for Decimal {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        Self::from(self.value + rhs.value)
+        if let Some(nan) = Self::propagate(&[&self, &rhs]) {
+            return nan;
+        }
+
+        match (self.is_infinite(), rhs.is_infinite()) {
+            (true, true) if self.negative != rhs.negative => Self::invalid("Error: Invalid operation."),
+            (true, _) => self,
+            (_, true) => rhs,
+            _ => match self.value.checked_add(rhs.value) {
+                Some(value) => Self::signed(value, self.negative && rhs.negative),
+                None => Self::overflow(f64::from(self.value) + f64::from(rhs.value) < 0.0),
+            },
+        }
     }
 }
 
@@ -173,7 +1469,11 @@ impl Sub for Decimal {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        Self::from(self.value - rhs.value)
+        if let Some(nan) = Self::propagate(&[&self, &rhs]) {
+            return nan;
+        }
+
+        self + -rhs
     }
 }
 
@@ -182,7 +1482,19 @@ impl Mul for Decimal {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        Self::from(self.value * rhs.value)
+        if let Some(nan) = Self::propagate(&[&self, &rhs]) {
+            return nan;
+        }
+
+        let negative = self.negative != rhs.negative;
+        match (self.kind, rhs.kind) {
+            (Kind::Finite, Kind::Finite) => match self.value.checked_mul(rhs.value) {
+                Some(value) => Self::signed(value, negative),
+                None => Self::overflow(negative),
+            },
+            _ if self.is_zero() || rhs.is_zero() => Self::invalid("Error: Invalid operation."),
+            _ => Self::special(Fraction::ZERO, Kind::Infinite, negative),
+        }
     }
 }
 
@@ -191,7 +1503,26 @@ impl Div for Decimal {
     type Output = Self;
 
     fn div(self, rhs: Self) -> Self::Output {
-        Self::from(self.value / rhs.value)
+        if let Some(nan) = Self::propagate(&[&self, &rhs]) {
+            return nan;
+        }
+
+        let negative = self.negative != rhs.negative;
+        match (self.kind, rhs.kind) {
+            // 0/0 is undefined
+            (Kind::Finite, Kind::Finite) if rhs.is_zero() && self.is_zero() => Self::invalid("Error: Divide by zero."),
+            (Kind::Finite, Kind::Finite) if rhs.is_zero() => {
+                Context::signal(Signal::DivisionByZero, "Error: Divide by zero.");
+                Self::special(Fraction::ZERO, Kind::Infinite, negative)
+            }
+            (Kind::Finite, Kind::Finite) => match self.value.checked_div(rhs.value) {
+                Some(value) => Self::signed(value, negative),
+                None => Self::overflow(negative),
+            },
+            (Kind::Infinite, Kind::Infinite) => Self::invalid("Error: Invalid operation."),
+            (Kind::Infinite, _) => Self::special(Fraction::ZERO, Kind::Infinite, negative),
+            _ => Self::signed(Fraction::ZERO, negative),
+        }
     }
 }
 
@@ -200,22 +1531,66 @@ impl Rem for Decimal {
     type Output = Self;
 
     fn rem(self, rhs: Self) -> Self::Output {
-        Self::from(self.value % rhs.value)
+        if let Some(nan) = Self::propagate(&[&self, &rhs]) {
+            return nan;
+        }
+
+        match (self.kind, rhs.kind) {
+            (Kind::Finite, Kind::Finite) if rhs.is_zero() => Self::invalid("Error: Divide by zero."),
+            (Kind::Finite, Kind::Finite) => match self.value.checked_rem(rhs.value) {
+                Some(value) => Self::signed(value, self.negative),
+                None => Self::overflow(self.negative),
+            },
+            (Kind::Infinite, _) => Self::invalid("Error: Invalid operation."),
+            _ => self,
+        }
     }
 }
 
+detail::impl_mixed_ops!(Int => Decimal, |x: Int| Decimal::from(Fraction::from_int(&x)));
+detail::impl_mixed_ops!(Fraction => Decimal, Decimal::from);
+
 /*
 Display
 */
 
 impl Display for Decimal {
+    /// Without precision, write the exact expansion with the repeating part like `0.1~6`.
+    /// With precision, write in fixed-point notation like Python's `format(x, '.3f')`,
+    /// or in general notation like Python's `format(x, '.3g')` if the `#` flag is specified.
+    /// The width, fill, alignment and sign flags are honored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pyinrs::Decimal;
+    /// let d = Decimal::from("1234.5678");
+    /// assert_eq!(format!("{:.2}", d), "1234.57");
+    /// assert_eq!(format!("{:#.3}", d), "1.23e+3");
+    /// assert_eq!(format!("{:>10.1}", -d), "   -1234.6");
+    /// assert_eq!(format!("{:.3e}", d), "1.235e+3");
+    /// ```
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        if let Some((start, length)) = Self::find_cyclic(self.value.numerator(), self.value.denominator()) {
-            let s = f64::from(self.value).to_string();
-            write!(f, "{}...", str::from_utf8(&s.as_bytes()[..=(s.find('.').unwrap() + start + length * 3)]).unwrap())
-        } else {
-            write!(f, "{}", f64::from(self.value))
-        }
+        let body = match (f.precision(), f.alternate()) {
+            (None, false) => self.abs().to_cyclic_string(CyclicNotation::Tilde),
+            (precision, false) => self.format_body('f', precision),
+            (precision, true) => self.format_body('g', precision),
+        };
+        self.pad(f, &body)
+    }
+}
+
+impl LowerExp for Decimal {
+    /// Write in scientific notation like Python's `format(x, '.3e')`.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.pad(f, &self.format_body('e', f.precision()))
+    }
+}
+
+impl UpperExp for Decimal {
+    /// Write in scientific notation like Python's `format(x, '.3E')`.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.pad(f, &self.format_body('E', f.precision()))
     }
 }
 
@@ -225,12 +1600,32 @@ Transform
 
 impl From<Decimal> for f64 {
     fn from(value: Decimal) -> Self {
-        f64::from(value.value)
+        let magnitude = match value.kind {
+            Kind::Finite => f64::from(value.value).abs(),
+            Kind::Infinite => f64::INFINITY,
+            Kind::QNaN | Kind::SNaN => f64::NAN,
+        };
+        if value.negative {
+            -magnitude
+        } else {
+            magnitude
+        }
     }
 }
 
 impl From<Decimal> for f32 {
     fn from(value: Decimal) -> Self {
-        f32::from(value.value)
+        f64::from(value) as f32
+    }
+}
+
+impl TryFrom<Decimal> for Int {
+    type Error = ConversionError;
+
+    fn try_from(value: Decimal) -> Result<Self, Self::Error> {
+        if !value.is_finite() {
+            return Err(ConversionError::NotFinite);
+        }
+        Int::try_from(value.value)
     }
 }
@@ -54,6 +54,40 @@ where
     }
 }
 
+// Implement `+ - * /` between a narrower type and a wider type, the result is of the wider type.
+// Only the owned operations are written here, `auto_ops` derives the reference and assignment forms.
+macro_rules! impl_mixed_ops {
+    ($narrow:ty => $wide:ty, $widen:expr) => {
+        $crate::detail::impl_mixed_ops!(@op $narrow => $wide, $widen, Add, add);
+        $crate::detail::impl_mixed_ops!(@op $narrow => $wide, $widen, Sub, sub);
+        $crate::detail::impl_mixed_ops!(@op $narrow => $wide, $widen, Mul, mul);
+        $crate::detail::impl_mixed_ops!(@op $narrow => $wide, $widen, Div, div);
+    };
+
+    (@op $narrow:ty => $wide:ty, $widen:expr, $Op:ident, $op:ident) => {
+        #[auto_impl_ops::auto_ops]
+        impl std::ops::$Op<$narrow> for $wide {
+            type Output = $wide;
+
+            fn $op(self, rhs: $narrow) -> Self::Output {
+                std::ops::$Op::$op(self, ($widen)(rhs))
+            }
+        }
+
+        // the narrower type cannot hold the result, so there are no assignment forms
+        #[auto_impl_ops::auto_ops(ref_ref, ref_val, val_ref)]
+        impl std::ops::$Op<$wide> for $narrow {
+            type Output = $wide;
+
+            fn $op(self, rhs: $wide) -> Self::Output {
+                std::ops::$Op::$op(($widen)(self), rhs)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_mixed_ops;
+
 #[inline]
 pub fn gcd<T: std::ops::Rem<Output = T> + Clone + Eq + Default>(mut a: T, mut b: T) -> T {
     // using Euclidean algorithm
@@ -0,0 +1,185 @@
+//! Fast Fourier transform and convolution over [`List<Complex>`](crate::List), with the same conventions as NumPy's `numpy.fft`.
+//!
+//! Power-of-two lengths use the iterative radix-2 algorithm, and other lengths use Bluestein's algorithm,
+//! so every transform takes O(n log n) time.
+//!
+//! ```
+//! use pyinrs::{fft, Complex, List};
+//!
+//! let x = List::from([1., 2., 3., 4.].map(Complex::from));
+//! assert_eq!(fft::fft(&x), List::from([(10., 0.), (-2., 2.), (-2., 0.), (-2., -2.)].map(Complex::from)));
+//! ```
+
+use std::f64::consts::PI;
+
+use crate::{detail, Complex, List};
+
+// Return `exp(sign * i * PI * num / den)`, where `num / den` is reduced into [0, 2) to keep the accuracy.
+fn unit(num: usize, den: usize, sign: f64) -> Complex {
+    let num = num % (2 * den);
+
+    // the multiples of a right angle are exact
+    if (2 * num).is_multiple_of(den) {
+        return match 2 * num / den {
+            0 => Complex::from((1., 0.)),
+            1 => Complex::from((0., sign)),
+            2 => Complex::from((-1., 0.)),
+            _ => Complex::from((0., -sign)),
+        };
+    }
+
+    let angle = sign * PI * num as f64 / den as f64;
+    Complex::from((angle.cos(), angle.sin()))
+}
+
+// Transform in place by the iterative radix-2 algorithm, the length must be a power of two.
+fn radix2(data: &mut [Complex], sign: f64) {
+    let n = data.len();
+
+    // bit-reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    let twiddles: Vec<Complex> = (0..n / 2).map(|k| unit(2 * k, n, sign)).collect();
+    let mut len = 2;
+    while len <= n {
+        let step = n / len;
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let u = data[start + k];
+                let v = data[start + k + len / 2] * twiddles[k * step];
+                data[start + k] = u + v;
+                data[start + k + len / 2] = u - v;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+// Transform any length by Bluestein's algorithm, which turns the transform into a power-of-two convolution.
+fn bluestein(data: &[Complex], sign: f64) -> Vec<Complex> {
+    let n = data.len();
+    let m = (2 * n - 1).next_power_of_two();
+
+    // chirp[k] = exp(sign * i * PI * k^2 / n)
+    let chirp: Vec<Complex> = (0..n).map(|k| unit(k * k, n, sign)).collect();
+
+    let mut a = vec![Complex::ZERO; m];
+    let mut b = vec![Complex::ZERO; m];
+    for k in 0..n {
+        a[k] = data[k] * chirp[k];
+        b[k] = chirp[k].conjugate();
+        if k > 0 {
+            b[m - k] = b[k];
+        }
+    }
+
+    radix2(&mut a, -1.);
+    radix2(&mut b, -1.);
+    for (x, y) in a.iter_mut().zip(&b) {
+        *x *= y;
+    }
+    radix2(&mut a, 1.);
+
+    (0..n).map(|k| a[k] * chirp[k] / m as f64).collect()
+}
+
+// Transform with the kernel `exp(sign * 2 * PI * i * j * k / n)`, without normalization.
+fn transform(data: &[Complex], sign: f64) -> Vec<Complex> {
+    detail::check_empty(data.len());
+
+    if data.len().is_power_of_two() {
+        let mut data = data.to_vec();
+        radix2(&mut data, sign);
+        data
+    } else {
+        bluestein(data, sign)
+    }
+}
+
+/// Compute the discrete Fourier transform of `x`, like `numpy.fft.fft(x)`.
+pub fn fft(x: &List<Complex>) -> List<Complex> {
+    List::from(transform(&x.data, -1.))
+}
+
+/// Compute the inverse discrete Fourier transform of `x`, like `numpy.fft.ifft(x)`.
+pub fn ifft(x: &List<Complex>) -> List<Complex> {
+    let n = x.data.len() as f64;
+    transform(&x.data, 1.).into_iter().map(|z| z / n).collect()
+}
+
+/// Compute the discrete Fourier transform of the real input `x`, like `numpy.fft.rfft(x)`.
+///
+/// Only the `x.len() / 2 + 1` non-negative frequency terms are returned, since the others are their conjugates.
+pub fn rfft(x: &List<f64>) -> List<Complex> {
+    let data: Vec<Complex> = x.iter().map(|&r| Complex::from(r)).collect();
+    transform(&data, -1.).into_iter().take(x.data.len() / 2 + 1).collect()
+}
+
+/// Compute the inverse of [`rfft`] with the output length `n`, like `numpy.fft.irfft(x, n)`.
+///
+/// The input is cropped or zero-padded to `n / 2 + 1` terms.
+pub fn irfft(x: &List<Complex>, n: usize) -> List<f64> {
+    detail::check_empty(n);
+
+    // rebuild the full Hermitian spectrum
+    let mut data = vec![Complex::ZERO; n];
+    for (k, &z) in x.iter().take(n / 2 + 1).enumerate() {
+        data[k] = z;
+        if k > 0 {
+            data[n - k] = z.conjugate();
+        }
+    }
+    transform(&data, 1.).into_iter().map(|z| z.real() / n as f64).collect()
+}
+
+// Return the linear convolution of two non-empty sequences by the power-of-two transforms.
+fn convolve_data(a: &[Complex], b: &[Complex]) -> Vec<Complex> {
+    let len = a.len() + b.len() - 1;
+    let m = len.next_power_of_two();
+
+    let (mut x, mut y) = (a.to_vec(), b.to_vec());
+    x.resize(m, Complex::ZERO);
+    y.resize(m, Complex::ZERO);
+    radix2(&mut x, -1.);
+    radix2(&mut y, -1.);
+    for (u, v) in x.iter_mut().zip(&y) {
+        *u *= v;
+    }
+    radix2(&mut x, 1.);
+
+    x.into_iter().take(len).map(|z| z / m as f64).collect()
+}
+
+/// Return the linear convolution of `a` and `b`, like `numpy.convolve(a, b)`.
+///
+/// The result has `a.len() + b.len() - 1` elements, or is empty if any input is empty.
+pub fn convolve(a: &List<f64>, b: &List<f64>) -> List<f64> {
+    if a.is_empty() || b.is_empty() {
+        return List::new();
+    }
+
+    let real = |x: &List<f64>| x.iter().map(|&r| Complex::from(r)).collect::<Vec<_>>();
+    convolve_data(&real(a), &real(b)).into_iter().map(|z| z.real()).collect()
+}
+
+/// Return the linear convolution of the complex sequences `a` and `b`, like `numpy.convolve(a, b)`.
+///
+/// The result has `a.len() + b.len() - 1` elements, or is empty if any input is empty.
+pub fn convolve_complex(a: &List<Complex>, b: &List<Complex>) -> List<Complex> {
+    if a.is_empty() || b.is_empty() {
+        return List::new();
+    }
+
+    List::from(convolve_data(&a.data, &b.data))
+}
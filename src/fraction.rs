@@ -1,11 +1,12 @@
 use std::{
     cmp::Ordering,
     fmt::Display,
+    num::IntErrorKind,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
     str::FromStr,
 };
 
-use crate::{detail, Decimal};
+use crate::{detail, Decimal, Int, List, Rounding};
 
 /// Fraction provides support for rational number arithmetic.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -46,6 +47,149 @@ impl Fraction {
         (*self).into()
     }
 
+    /// Return the greatest integer less than or equal to self, like Python's `math.floor()`.
+    pub fn floor(&self) -> Int {
+        Int::from(self.num.div_euclid(self.den))
+    }
+
+    /// Return the least integer greater than or equal to self, like Python's `math.ceil()`.
+    pub fn ceil(&self) -> Int {
+        // the quotient can't be i128::MAX with a non-zero remainder, so it won't overflow
+        Int::from(self.num.div_euclid(self.den) + (self.num.rem_euclid(self.den) != 0) as i128)
+    }
+
+    /// Return the integer part of self, rounding toward zero, like Python's `math.trunc()`.
+    pub fn trunc(&self) -> Int {
+        Int::from(self.num / self.den)
+    }
+
+    /// Return the nearest integer to self, rounding half to even, like Python's `round()`.
+    pub fn round(&self) -> Int {
+        Int::from(Self::round_integer(self.num, self.den, Rounding::HalfEven))
+    }
+
+    /// Return self rounded to `ndigits` decimal places, rounding half to even, like Python's `round(x, ndigits)`.
+    /// `ndigits` can be negative.
+    pub fn round_to(&self, ndigits: i32) -> Self {
+        self.round_with(ndigits, Rounding::HalfEven)
+    }
+
+    /// Return `base**exp`, `exp` can be negative.
+    pub fn pow(base: Self, exp: i32) -> Self {
+        if exp < 0 {
+            detail::check_zero(base.num);
+        }
+
+        Self::checked_pow(base, exp).unwrap_or_else(|| panic!("Error: The result does not fit in a fraction."))
+    }
+
+    /// Return `base**exp`, or `None` if overflow occurred or `base` is zero while `exp` is negative.
+    pub fn checked_pow(base: Self, exp: i32) -> Option<Self> {
+        // num and den are coprime, so are their powers
+        let num = base.num.checked_pow(exp.unsigned_abs())?;
+        let den = base.den.checked_pow(exp.unsigned_abs())?;
+        match (exp >= 0, num.cmp(&0)) {
+            (true, _) => Some(Self { num, den }),
+            (false, Ordering::Equal) => None,
+            (false, Ordering::Greater) => Some(Self { num: den, den: num }),
+            (false, Ordering::Less) => Some(Self {
+                num: den.checked_neg()?,
+                den: num.checked_neg()?,
+            }),
+        }
+    }
+
+    /// Return `base**exp` with a rational exponent if the result is rational, otherwise `None`.
+    /// `None` is also returned if overflow occurred.
+    ///
+    /// For example, `(4/9)**(1/2)` is `2/3` but `2**(1/2)` is `None`.
+    /// A negative base only has a root when the denominator of `exp` is odd, e.g. `(-8)**(1/3)` is `-2`.
+    pub fn pow_rational(base: Self, exp: Self) -> Option<Self> {
+        if exp.num < 0 {
+            detail::check_zero(base.num);
+        }
+
+        if base.num < 0 && exp.den % 2 == 0 {
+            return None;
+        }
+
+        let num = i128::try_from(Self::exact_root(base.num.unsigned_abs(), exp.den)?).ok()?;
+        let den = i128::try_from(Self::exact_root(base.den.unsigned_abs(), exp.den)?).ok()?;
+        let root = Self {
+            num: if base.num < 0 { -num } else { num },
+            den,
+        };
+
+        // a huge exponent only fits for the roots 0, 1 and -1, so keep its sign and parity
+        let exp = i32::try_from(exp.num).unwrap_or(match (exp.num > 0, exp.num % 2 == 0) {
+            (true, true) => i32::MAX - 1,
+            (true, false) => i32::MAX,
+            (false, true) => i32::MIN,
+            (false, false) => i32::MIN + 1,
+        });
+        Self::checked_pow(root, exp)
+    }
+
+    /// Return self as a mixed number, e.g. `1 1/2` for `3/2`.
+    /// This is the same as the alternate format `{:#}`.
+    pub fn to_mixed_string(&self) -> String {
+        let (whole, part) = (self.num / self.den, (self.num % self.den).abs());
+        match (whole, part) {
+            (_, 0) => whole.to_string(),
+            (0, _) => self.to_string(),
+            _ => format!("{} {}/{}", whole, part, self.den),
+        }
+    }
+
+    /// Return self using Unicode vulgar fractions when available, e.g. `½`, or superscript and subscript digits otherwise, e.g. `¹¹/₁₂`.
+    pub fn to_unicode_string(&self) -> String {
+        const VULGAR: [(i128, i128, char); 18] = [
+            (1, 2, '½'),
+            (1, 3, '⅓'),
+            (2, 3, '⅔'),
+            (1, 4, '¼'),
+            (3, 4, '¾'),
+            (1, 5, '⅕'),
+            (2, 5, '⅖'),
+            (3, 5, '⅗'),
+            (4, 5, '⅘'),
+            (1, 6, '⅙'),
+            (5, 6, '⅚'),
+            (1, 7, '⅐'),
+            (1, 8, '⅛'),
+            (3, 8, '⅜'),
+            (5, 8, '⅝'),
+            (7, 8, '⅞'),
+            (1, 9, '⅑'),
+            (1, 10, '⅒'),
+        ];
+        const SUPERSCRIPT: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+        const SUBSCRIPT: [char; 10] = ['₀', '₁', '₂', '₃', '₄', '₅', '₆', '₇', '₈', '₉'];
+
+        if self.den == 1 {
+            return self.num.to_string();
+        }
+
+        let sign = if self.num < 0 { "-" } else { "" };
+        let num = self.num.unsigned_abs();
+        if let Some(&(_, _, c)) = VULGAR.iter().find(|&&(n, d, _)| n as u128 == num && d == self.den) {
+            return format!("{sign}{c}");
+        }
+
+        let map = |n: u128, table: &[char; 10]| n.to_string().bytes().map(|b| table[(b - b'0') as usize]).collect::<String>();
+        format!("{sign}{}/{}", map(num, &SUPERSCRIPT), map(self.den as u128, &SUBSCRIPT))
+    }
+
+    /// Return self in LaTeX, e.g. `\frac{1}{2}`.
+    pub fn to_latex_string(&self) -> String {
+        if self.den == 1 {
+            return self.num.to_string();
+        }
+
+        let sign = if self.num < 0 { "-" } else { "" };
+        format!("{sign}\\frac{{{}}}{{{}}}", self.num.unsigned_abs(), self.den)
+    }
+
     /// Calculate the greatest common divisor of two fractions.
     pub fn gcd(a: Self, b: Self) -> Self {
         detail::gcd(a.abs(), b.abs())
@@ -60,6 +204,313 @@ impl Fraction {
         (a * b).abs() / Self::gcd(a, b) // LCM = |a * b| / GCD
     }
 
+    /// Return the mediant of two fractions, i.e. `(a.num + b.num) / (a.den + b.den)`.
+    pub fn mediant(a: Self, b: Self) -> Self {
+        Self::from((a.num + b.num, a.den + b.den))
+    }
+
+    /// Generate the Farey sequence of order `n`, i.e. all reduced fractions in [0, 1] with denominators <= `n`, in ascending order.
+    pub fn farey(n: i128) -> List<Self> {
+        if n < 1 {
+            panic!("Error: Require n >= 1 for farey(n).");
+        }
+
+        // a/b and c/d are two adjacent terms, the next term is (k*c - a)/(k*d - b)
+        let (mut a, mut b, mut c, mut d) = (0, 1, 1, n);
+        let mut sequence = List::from([Self::new()]);
+        while c <= n {
+            let k = (n + b) / d;
+            (a, b, c, d) = (c, d, k * c - a, k * d - b);
+            sequence.push(Self { num: a, den: b });
+        }
+        sequence
+    }
+
+    /// Return the path from `1` to self in the Stern-Brocot tree, `L` for left and `R` for right, e.g. `RL` for `3/2`.
+    pub fn stern_brocot_path(&self) -> String {
+        if self.num <= 0 {
+            panic!("Error: Require self > 0 for stern_brocot_path().");
+        }
+
+        // go down the tree by runs of the same direction, like the Euclidean algorithm
+        let (mut p, mut q) = (self.num, self.den);
+        let mut path = String::new();
+        while p != q {
+            if p > q {
+                let k = (p - 1) / q;
+                path.push_str(&"R".repeat(k as usize));
+                p -= k * q;
+            } else {
+                let k = (q - 1) / p;
+                path.push_str(&"L".repeat(k as usize));
+                q -= k * p;
+            }
+        }
+        path
+    }
+
+    /// Return the fraction at the end of `path` from `1` in the Stern-Brocot tree, `L` for left and `R` for right.
+    pub fn from_stern_brocot_path(path: &str) -> Self {
+        // the node is the mediant of its bounds a/b and c/d, starting from 0/1 and 1/0
+        let (mut a, mut b, mut c, mut d) = (0, 1, 1, 0);
+        for step in path.chars() {
+            match step {
+                'L' => (c, d) = (a + c, b + d),
+                'R' => (a, b) = (a + c, b + d),
+                _ => panic!("Error: Wrong Stern-Brocot path."),
+            }
+        }
+        Self { num: a + c, den: b + d }
+    }
+
+    /// Return the simplest fraction in the closed interval between `a` and `b`, i.e. the one with the smallest denominator (and then the smallest absolute numerator).
+    pub fn simplest_between(a: Self, b: Self) -> Self {
+        let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+        if lo.num <= 0 && hi.num >= 0 {
+            return Self::new();
+        }
+        if hi.num < 0 {
+            return -Self::simplest_between(-hi, -lo);
+        }
+
+        // 0 < lo <= hi, take the least integer in the interval if any
+        let floor = lo.num.div_euclid(lo.den);
+        let ceil = Self::from_integer(floor + (lo.num.rem_euclid(lo.den) != 0) as i128);
+        if ceil <= hi {
+            return ceil;
+        }
+
+        // otherwise lo and hi are in (floor, floor + 1), recurse on the reciprocals of the fractional parts
+        let floor = Self::from_integer(floor);
+        floor + Self::from(1) / Self::simplest_between(Self::from(1) / (hi - floor), Self::from(1) / (lo - floor))
+    }
+
+    /// Construct a fraction from a floating-point number exactly, like Python's `float.as_integer_ratio()`.
+    ///
+    /// Return an error if the value is not finite or does not fit in `i128`.
+    pub fn from_float(value: f64) -> Result<Self, ConversionError> {
+        if !value.is_finite() {
+            return Err(ConversionError::NotFinite);
+        }
+
+        if value == 0.0 {
+            return Ok(Self::new());
+        }
+
+        // value = (-1)^sign * mantissa * 2^exponent
+        let bits = value.to_bits();
+        let sign = if bits >> 63 == 0 { 1 } else { -1 };
+        let biased = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = (bits & ((1 << 52) - 1)) as i128;
+        let (mut mantissa, mut exponent) = if biased == 0 {
+            (fraction, -1074) // subnormal
+        } else {
+            (fraction | (1 << 52), biased - 1075)
+        };
+
+        // remove common factors of 2
+        let zeros = (mantissa.trailing_zeros() as i32).min((-exponent).max(0));
+        mantissa >>= zeros;
+        exponent += zeros;
+
+        if exponent >= 0 {
+            // mantissa < 2^53, so the numerator fits if there are enough leading zeros
+            if exponent as u32 >= mantissa.leading_zeros() {
+                return Err(ConversionError::Overflow);
+            }
+            Ok(Self::from_integer(sign * (mantissa << exponent)))
+        } else {
+            // the denominator 2^-exponent must fit in i128
+            if -exponent > 126 {
+                return Err(ConversionError::Overflow);
+            }
+            Ok(Self {
+                num: sign * mantissa,
+                den: 1 << -exponent,
+            })
+        }
+    }
+
+    /// Construct a fraction from a floating-point number rounded to `f64::DIGITS` decimal places.
+    ///
+    /// This is what `From<f64>` does, so `Fraction::from_float_rounded(0.1)` is `1/10`.
+    pub fn from_float_rounded(value: f64) -> Self {
+        if !value.is_finite() {
+            panic!("Error: Invalid floating-point number.");
+        }
+
+        let int_part = value.floor();
+        let dec_part = value - int_part;
+        let precision = i128::pow(10, f64::DIGITS);
+
+        let gcd = detail::gcd((dec_part * (precision as f64)).round() as i128, precision);
+        let mut num = (dec_part * precision as f64).round() as i128 / gcd;
+        let den = precision / gcd;
+        num += int_part as i128 * den;
+
+        Self { num, den }
+    }
+
+    // Parse an integer, mapping the error kind.
+    fn parse_integer(s: &str) -> Result<i128, ParseFractionError> {
+        s.parse().map_err(|e: std::num::ParseIntError| match e.kind() {
+            IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => ParseFractionError::Overflow,
+            _ => ParseFractionError::InvalidDigit,
+        })
+    }
+
+    // Parse `[+-]digits[.digits][(e|E)[+-]digits]`, integer is a special case of it.
+    fn parse_decimal(s: &str) -> Result<Self, ParseFractionError> {
+        let (mantissa, exp) = match s.find(['e', 'E']) {
+            Some(i) => (&s[..i], Self::parse_integer(&s[i + 1..])?),
+            None => (s, 0),
+        };
+
+        let (negative, mantissa) = match mantissa.strip_prefix('-') {
+            Some(m) => (true, m),
+            None => (false, mantissa.strip_prefix('+').unwrap_or(mantissa)),
+        };
+        let (int, dec) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        if int.is_empty() && dec.is_empty() || !int.bytes().chain(dec.bytes()).all(|c| c.is_ascii_digit()) {
+            return Err(ParseFractionError::InvalidDigit);
+        }
+
+        // value = coef * 10^exp, trailing zeros are moved into the exponent to avoid needless overflow
+        let digits = format!("{int}{dec}");
+        let trimmed = digits.trim_end_matches('0');
+        if trimmed.is_empty() {
+            return Ok(Self::new());
+        }
+        let exp = exp
+            .checked_sub(dec.len() as i128)
+            .and_then(|e| e.checked_add((digits.len() - trimmed.len()) as i128))
+            .ok_or(ParseFractionError::Overflow)?;
+        let coef = Self::parse_integer(trimmed)?;
+        let coef = if negative { -coef } else { coef };
+
+        let scale = u32::try_from(exp.unsigned_abs())
+            .ok()
+            .and_then(|e| 10i128.checked_pow(e))
+            .ok_or(ParseFractionError::Overflow)?;
+        if exp >= 0 {
+            Ok(Self::from_integer(coef.checked_mul(scale).ok_or(ParseFractionError::Overflow)?))
+        } else {
+            Ok(Self::from((coef, scale)))
+        }
+    }
+
+    // Return the k-th root of n if it is an integer.
+    fn exact_root(n: u128, k: i128) -> Option<u128> {
+        if n <= 1 || k == 1 {
+            return Some(n);
+        }
+
+        // 2**128 > n, so for k >= 128 the root is between 1 and 2
+        let k = u32::try_from(k).ok().filter(|&k| k < 128)?;
+
+        // binary search
+        let (mut lo, mut hi) = (1u128, 1u128 << (128 / k + 1));
+        while lo <= hi {
+            let mid = lo + (hi - lo) / 2;
+            match mid.checked_pow(k).map_or(Ordering::Greater, |p| p.cmp(&n)) {
+                Ordering::Less => lo = mid + 1,
+                Ordering::Greater => hi = mid - 1,
+                Ordering::Equal => return Some(mid),
+            }
+        }
+        None
+    }
+
+    // Round num/den (den > 0) to an integer with the rounding mode.
+    fn round_integer(num: i128, den: i128, rounding: Rounding) -> i128 {
+        let (q, r) = (num / den, (num % den).abs());
+        let last = (q % 10).unsigned_abs() as u8;
+        if rounding.round_up(num < 0, last, r.cmp(&(den - r)), r != 0) {
+            q + num.signum()
+        } else {
+            q
+        }
+    }
+
+    // Round to `ndigits` decimal places with the rounding mode, `ndigits` can be negative.
+    pub(crate) fn round_with(&self, ndigits: i32, rounding: Rounding) -> Self {
+        self.checked_round_with(ndigits, rounding)
+            .unwrap_or_else(|| panic!("Error: The result does not fit in a fraction."))
+    }
+
+    // Round to `ndigits` decimal places with the rounding mode, or `None` if the result does not fit.
+    pub(crate) fn checked_round_with(&self, ndigits: i32, rounding: Rounding) -> Option<Self> {
+        let shift = 10i128.checked_pow(ndigits.unsigned_abs());
+        if ndigits >= 0 {
+            // a terminating decimal with no more than `ndigits` places is unchanged
+            let (mut den, mut twos, mut fives) = (self.den, 0, 0);
+            while den % 2 == 0 {
+                (den, twos) = (den / 2, twos + 1);
+            }
+            while den % 5 == 0 {
+                (den, fives) = (den / 5, fives + 1);
+            }
+            if den == 1 && twos.max(fives) <= ndigits {
+                return Some(*self);
+            }
+
+            let shift = shift?;
+            return Some(Self::from((Self::round_integer(self.num.checked_mul(shift)?, self.den, rounding), shift)));
+        }
+
+        if let Some(den) = shift.and_then(|shift| self.den.checked_mul(shift)) {
+            return Self::round_integer(self.num, den, rounding).checked_mul(shift?).map(Self::from);
+        }
+
+        // the unit does not fit, so self is less than one unit and rounds to zero or one unit
+        let unit = shift.and_then(|shift| (self.den as u128).checked_mul(shift as u128));
+        let half = unit.map_or(Ordering::Less, |unit| self.num.unsigned_abs().saturating_mul(2).cmp(&unit));
+        if rounding.round_up(self.num < 0, 0, half, self.num != 0) {
+            shift.map(|shift| Self::from(self.num.signum() * shift))
+        } else {
+            Some(Self::new())
+        }
+    }
+
+    // Add over the least common denominator, or `None` if the result does not fit.
+    pub(crate) fn checked_add(self, rhs: Self) -> Option<Self> {
+        let g = detail::gcd(self.den, rhs.den);
+        let (a, b) = (self.den / g, rhs.den / g);
+        let num = self.num.checked_mul(b)?.checked_add(rhs.num.checked_mul(a)?)?;
+        Some(Self::from((num, self.den.checked_mul(b)?)))
+    }
+
+    // Divide by a nonzero fraction with the cross cancellation, or `None` if the result does not fit.
+    pub(crate) fn checked_div(self, rhs: Self) -> Option<Self> {
+        let reciprocal = Self {
+            num: rhs.den * rhs.num.signum(),
+            den: rhs.num.checked_abs()?,
+        };
+        self.checked_mul(reciprocal)
+    }
+
+    // Multiply with the cross cancellation, or `None` if the result does not fit.
+    pub(crate) fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let (g1, g2) = (detail::gcd(self.num.abs(), rhs.den), detail::gcd(rhs.num.abs(), self.den));
+        Some(Self {
+            num: (self.num / g1).checked_mul(rhs.num / g2)?,
+            den: (self.den / g2).checked_mul(rhs.den / g1)?,
+        })
+    }
+
+    // Take the remainder over the least common denominator, or `None` if the result does not fit.
+    pub(crate) fn checked_rem(self, rhs: Self) -> Option<Self> {
+        let g = detail::gcd(self.den, rhs.den);
+        let (a, b) = (self.den / g, rhs.den / g);
+        let num = self.num.checked_mul(b)?.checked_rem(rhs.num.checked_mul(a)?)?;
+        Some(Self::from((num, self.den.checked_mul(b)?)))
+    }
+
+    // Convert a big integer, panic if it does not fit in i128.
+    pub(crate) fn from_int(value: &Int) -> Self {
+        Self::try_from(value).unwrap_or_else(|_| panic!("Error: The integer is too large to convert to a fraction."))
+    }
+
     fn from_integer<T: Into<i128>>(value: T) -> Self {
         Self { num: value.into(), den: 1 }
     }
@@ -84,6 +535,7 @@ impl Fraction {
         Self { num, den }
     }
 
+    pub(crate) const ZERO: Fraction = Fraction { num: 0, den: 1 };
     pub const MAX: Fraction = Fraction { num: i128::MAX, den: 1 };
     pub const MIN: Fraction = Fraction { num: i128::MIN, den: 1 };
     pub const EPSILON: Fraction = Fraction { num: 1, den: i128::MAX };
@@ -114,20 +566,7 @@ impl_from_integer!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
 
 impl From<f64> for Fraction {
     fn from(value: f64) -> Self {
-        if !value.is_finite() {
-            panic!("Error: Invalid floating-point number.");
-        }
-
-        let int_part = value.floor();
-        let dec_part = value - int_part;
-        let precision = i128::pow(10, f64::DIGITS);
-
-        let gcd = detail::gcd((dec_part * (precision as f64)).round() as i128, precision);
-        let mut num = (dec_part * precision as f64).round() as i128 / gcd;
-        let den = precision / gcd;
-        num += int_part as i128 * den;
-
-        Self { num, den }
+        Fraction::from_float_rounded(value)
     }
 }
 
@@ -137,6 +576,22 @@ impl From<f32> for Fraction {
     }
 }
 
+impl TryFrom<&Int> for Fraction {
+    type Error = ConversionError;
+
+    fn try_from(value: &Int) -> Result<Self, Self::Error> {
+        value.to_string().parse::<i128>().map(Self::from_integer).map_err(|_| ConversionError::Overflow)
+    }
+}
+
+impl TryFrom<Int> for Fraction {
+    type Error = ConversionError;
+
+    fn try_from(value: Int) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
 impl From<Decimal> for Fraction {
     fn from(value: Decimal) -> Self {
         value.as_fraction()
@@ -145,28 +600,90 @@ impl From<Decimal> for Fraction {
 
 impl From<&str> for Fraction {
     fn from(value: &str) -> Self {
-        Self::from_str(value).unwrap_or_else(|_| panic!("expect format `numerator/denominator` but got `{}`", value))
+        Self::from_str(value).unwrap_or_else(|_| panic!("expect format `[whole ]numerator/denominator` or `decimal` but got `{}`", value))
     }
 }
 
+/// An error which can be returned when parsing a fraction.
 #[derive(Debug, PartialEq, Eq)]
-pub struct ParseFractionError;
+pub enum ParseFractionError {
+    /// The string is empty.
+    Empty,
+
+    /// The string contains an invalid digit or is not in a supported format.
+    InvalidDigit,
+
+    /// The denominator is zero.
+    ZeroDenominator,
+
+    /// The numerator or denominator does not fit in `i128`.
+    Overflow,
+}
+
+/// Error returned when a number cannot be converted exactly.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ConversionError {
+    /// The value is infinite or NaN.
+    NotFinite,
+
+    /// The value does not fit in the target type.
+    Overflow,
+
+    /// The value has a fractional part.
+    NotInteger,
+
+    /// The value has a non-zero imaginary part.
+    NotReal,
+}
 
 impl FromStr for Fraction {
     type Err = ParseFractionError;
 
+    /// Parse a fraction from one of the following formats, surrounded by optional whitespace:
+    ///
+    /// - integer: `-3`
+    /// - ratio: `3/4`, `3 / -4`
+    /// - mixed number: `1 1/2`, `-1 1/2` (the sign applies to the whole number)
+    /// - decimal: `1.25`, `-.5`
+    /// - scientific notation: `1.5e-3`, `2E10`
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let s = s.trim();
-        if let Ok(num) = s.parse() {
-            return Ok(Self { num, den: 1 });
+        if s.is_empty() {
+            return Err(ParseFractionError::Empty);
         }
 
-        let (num, den) = s.split_once('/').ok_or(ParseFractionError)?;
+        let Some((left, den)) = s.split_once('/') else {
+            return Self::parse_decimal(s);
+        };
+
+        let den = Self::parse_integer(den.trim())?;
+        if den == 0 {
+            return Err(ParseFractionError::ZeroDenominator);
+        }
 
-        let num = num.parse::<i128>().map_err(|_| ParseFractionError)?;
-        let den = den.parse::<i128>().map_err(|_| ParseFractionError)?;
+        let left = left.trim();
+        let Some((whole, num)) = left.split_once(char::is_whitespace) else {
+            return Ok(Self::from((Self::parse_integer(left)?, den)));
+        };
 
-        Ok(Self::from((num, den)))
+        // mixed number, only the whole number can be signed
+        let num = num.trim_start();
+        if num.starts_with(['+', '-']) || den < 0 {
+            return Err(ParseFractionError::InvalidDigit);
+        }
+        let part = Self::from((Self::parse_integer(num)?, den));
+        let whole_num = Self::parse_integer(whole)?.checked_mul(part.den).ok_or(ParseFractionError::Overflow)?;
+        let num = if whole.starts_with('-') {
+            whole_num.checked_sub(part.num)
+        } else {
+            whole_num.checked_add(part.num)
+        };
+
+        // gcd(w * d + n, d) == gcd(n, d) == 1, so it is already simplified
+        Ok(Self {
+            num: num.ok_or(ParseFractionError::Overflow)?,
+            den: part.den,
+        })
     }
 }
 
@@ -251,13 +768,17 @@ impl Rem for Fraction {
     }
 }
 
+detail::impl_mixed_ops!(Int => Fraction, |x: Int| Fraction::from_int(&x));
+
 /*
 Display
 */
 
 impl Display for Fraction {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        if self.den == 1 {
+        if f.alternate() {
+            write!(f, "{}", self.to_mixed_string())
+        } else if self.den == 1 {
             write!(f, "{}", self.num)
         } else {
             write!(f, "{}/{}", self.num, self.den)
@@ -280,3 +801,15 @@ impl From<Fraction> for f32 {
         value.num as f32 / value.den as f32
     }
 }
+
+impl TryFrom<Fraction> for Int {
+    type Error = ConversionError;
+
+    fn try_from(value: Fraction) -> Result<Self, Self::Error> {
+        if value.den != 1 {
+            return Err(ConversionError::NotInteger);
+        }
+
+        Ok(Int::from(value.num))
+    }
+}
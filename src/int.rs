@@ -884,3 +884,20 @@ impl Display for Int {
         Ok(())
     }
 }
+
+/*
+Transform
+*/
+
+impl From<&Int> for f64 {
+    fn from(value: &Int) -> Self {
+        // correctly rounded, and infinite if too large
+        value.to_string().parse().unwrap()
+    }
+}
+
+impl From<Int> for f64 {
+    fn from(value: Int) -> Self {
+        f64::from(&value)
+    }
+}
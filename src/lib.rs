@@ -2,22 +2,32 @@
 
 mod detail;
 
+pub mod fft;
+
+mod bigdecimal;
 mod complex;
+mod context;
 mod decimal;
 mod deque;
 mod dict;
 mod fraction;
 mod int;
 mod list;
+mod number;
+mod poly;
 mod set;
 mod str;
 
+pub use bigdecimal::{BigDecimal, ParseBigDecimalError};
 pub use complex::Complex;
-pub use decimal::Decimal;
+pub use context::{Context, Rounding, Signal, Signals};
+pub use decimal::{CyclicNotation, Decimal, ParseDecimalError};
 pub use deque::Deque;
 pub use dict::Dict;
-pub use fraction::Fraction;
+pub use fraction::{ConversionError, Fraction, ParseFractionError};
 pub use int::Int;
 pub use list::List;
+pub use number::{Field, Number};
+pub use poly::Poly;
 pub use set::Set;
 pub use str::Str;
@@ -0,0 +1,21 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{Fraction, Int};
+
+/// Number is a numeric type that can be the parts of a [`Complex`](crate::Complex) or the coefficients of a [`Poly`](crate::Poly).
+///
+/// The default value is the zero of the type.
+pub trait Number: Clone + Default + PartialEq + From<i32> + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self> {}
+
+impl Number for f64 {}
+
+impl Number for Int {}
+
+impl Number for Fraction {}
+
+/// Field is a [`Number`] that is closed under division by nonzero values.
+pub trait Field: Number + Div<Output = Self> {}
+
+impl Field for f64 {}
+
+impl Field for Fraction {}
@@ -0,0 +1,401 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+};
+
+use crate::{detail, Complex, Field, Fraction, Int, List, Number};
+
+/// Poly provides support for univariate polynomial arithmetic, like `numpy.polynomial.Polynomial`.
+///
+/// The coefficients are stored in ascending order of degree, `Poly::from([c0, c1, c2])` is `c0 + c1*x + c2*x^2`.
+/// The coefficients are exact for `Int` and `Fraction`.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Hash)]
+pub struct Poly<T> {
+    // Coefficients in ascending order of degree, without trailing zeros.
+    coeffs: Vec<T>,
+}
+
+impl<T: Number> Poly<T> {
+    /// Construct a new zero polynomial.
+    pub fn new() -> Self {
+        Self { coeffs: Vec::new() }
+    }
+
+    // Remove the trailing zeros to keep the representation unique.
+    fn normalize(mut self) -> Self {
+        while self.coeffs.last().is_some_and(|c| *c == T::default()) {
+            self.coeffs.pop();
+        }
+        self
+    }
+
+    /// Return the degree of the polynomial, or -1 for the zero polynomial.
+    pub fn degree(&self) -> i32 {
+        self.coeffs.len() as i32 - 1
+    }
+
+    /// Return the coefficients in ascending order of degree.
+    pub fn coefficients(&self) -> &[T] {
+        &self.coeffs
+    }
+
+    /// Return `true` if this is the zero polynomial.
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    /// Evaluate the polynomial at `x` by Horner's method.
+    pub fn eval(&self, x: &T) -> T {
+        self.coeffs.iter().rev().fold(T::default(), |acc, c| acc * x.clone() + c.clone())
+    }
+
+    /// Return the derivative of the polynomial.
+    pub fn derivative(&self) -> Self {
+        let coeffs = self.coeffs.iter().enumerate().skip(1).map(|(i, c)| c.clone() * T::from(i as i32)).collect();
+        Self { coeffs }.normalize()
+    }
+
+    /// Return the roots of the polynomial with multiplicity, sorted by the real parts and then the imaginary parts.
+    ///
+    /// The roots are approximated by the Aberth-Ehrlich method, the zero polynomial and the constants have no roots.
+    pub fn roots(&self) -> List<Complex>
+    where
+        f64: From<T>,
+    {
+        let coeffs: Vec<f64> = self.coeffs.iter().map(|c| f64::from(c.clone())).collect();
+
+        // zero roots are exact
+        let zeros = coeffs.iter().take_while(|&&c| c == 0.).count();
+        let mut roots = vec![Complex::ZERO; zeros.min(coeffs.len().saturating_sub(1))];
+        if coeffs.len() > zeros + 1 {
+            roots.extend(aberth(&coeffs[zeros..]));
+        }
+
+        roots.sort_by(|a, b| a.real().total_cmp(&b.real()).then(a.imag().total_cmp(&b.imag())));
+        List::from(roots)
+    }
+
+    /// Return the rational roots of the polynomial with multiplicity in ascending order, which are verified exactly.
+    ///
+    /// The zero polynomial and the constants have no roots.
+    pub fn rational_roots(&self) -> List<Fraction>
+    where
+        Fraction: TryFrom<T>,
+    {
+        let coeffs = self.coeffs.iter().map(|c| Fraction::try_from(c.clone()).ok());
+        let mut rest = Poly::from(
+            coeffs
+                .collect::<Option<Vec<_>>>()
+                .unwrap_or_else(|| panic!("Error: The coefficients do not fit in fractions.")),
+        );
+        if rest.degree() <= 0 {
+            return List::new();
+        }
+
+        // zero roots are exact
+        let zeros = rest.coeffs.iter().take_while(|c| **c == Fraction::new()).count();
+        let mut roots = vec![Fraction::new(); zeros];
+        rest.coeffs.drain(..zeros);
+
+        // the roots of the square-free part are simple, so the approximations are accurate enough to be recovered
+        let square_free = &rest / &Poly::gcd(&rest, &rest.derivative());
+
+        // clear the denominators, then a rational root `p/q` in lowest terms has `q | a[n]` and `p | a[0]` by the rational root theorem,
+        // so `a[n] * r` and `a[0] / r` are integers, which are recovered by rounding the approximations whatever the scale of the root
+        let lcm = square_free.coeffs.iter().fold(Int::from(1), |l, c| Int::lcm(&l, &Int::from(c.denominator())));
+        let ints: Vec<Int> = square_free
+            .coeffs
+            .iter()
+            .map(|c| Int::from(c.numerator()) * (&lcm / Int::from(c.denominator())))
+            .collect();
+        let (first, last) = (&ints[0], &ints[ints.len() - 1]);
+
+        // a[0] * q^n + a[1] * p * q^(n-1) + ... + a[n] * p^n == 0, exactly
+        let is_root = |p: &Int, q: &Int| {
+            let (mut acc, mut power) = (Int::new(), Int::from(1));
+            for a in ints.iter().rev() {
+                acc = acc * p + a * &power;
+                power *= q;
+            }
+            acc.is_zero()
+        };
+
+        let mut candidates = Vec::new();
+        for z in square_free.roots() {
+            if z.imag().abs() > 1e-9 * z.abs().max(1.) {
+                continue;
+            }
+
+            let x = z.real();
+            let pairs = [
+                (Int::from((f64::from(last) * x).round() as i128), last.clone()),
+                (first.clone(), Int::from((f64::from(first) / x).round() as i128)),
+            ];
+            for (p, q) in pairs {
+                if q.is_zero() || !is_root(&p, &q) {
+                    continue;
+                }
+
+                let gcd = Int::gcd(&p, &q);
+                if let (Ok(p), Ok(q)) = (<Fraction as TryFrom<Int>>::try_from(&p / &gcd), <Fraction as TryFrom<Int>>::try_from(&q / &gcd)) {
+                    let r = p / q;
+                    if !candidates.contains(&r) {
+                        candidates.push(r);
+                    }
+                    break;
+                }
+            }
+        }
+
+        // divide out each root as many times as possible to get the multiplicity
+        for r in candidates {
+            let factor = Poly::from([Fraction::from(-r.numerator()), Fraction::from(r.denominator())]);
+            loop {
+                let (q, rem) = rest.divmod(&factor);
+                if !rem.is_zero() {
+                    break;
+                }
+                roots.push(r);
+                rest = q;
+            }
+        }
+
+        roots.sort();
+        List::from(roots)
+    }
+}
+
+impl<T: Field> Poly<T> {
+    /// Return the antiderivative of the polynomial with the zero constant term.
+    pub fn integral(&self) -> Self {
+        let terms = self.coeffs.iter().enumerate().map(|(i, c)| c.clone() / T::from(i as i32 + 1));
+        Self {
+            coeffs: std::iter::once(T::default()).chain(terms).collect(),
+        }
+        .normalize()
+    }
+
+    /// Return the polynomial divided by its leading coefficient, the zero polynomial is unchanged.
+    pub fn monic(&self) -> Self {
+        match self.coeffs.last() {
+            Some(lead) => Self::from(self.coeffs.iter().map(|c| c.clone() / lead.clone()).collect::<Vec<_>>()),
+            None => self.clone(),
+        }
+    }
+
+    /// Return the quotient and remainder simultaneously.
+    /// `self == (self / rhs) * rhs + self % rhs` and the degree of the remainder is less than that of `rhs`.
+    pub fn divmod(&self, rhs: &Self) -> (Self, Self) {
+        detail::check_zero(rhs.clone());
+
+        let d = rhs.coeffs.len();
+        if self.coeffs.len() < d {
+            return (Self::new(), self.clone());
+        }
+
+        let lead = rhs.coeffs[d - 1].clone();
+        let mut rem = self.coeffs.clone();
+        let mut quot = vec![T::default(); rem.len() - d + 1];
+        for i in (0..quot.len()).rev() {
+            let q = rem[i + d - 1].clone() / lead.clone();
+            for (j, c) in rhs.coeffs.iter().enumerate() {
+                rem[i + j] = rem[i + j].clone() - q.clone() * c.clone();
+            }
+            quot[i] = q;
+        }
+
+        // the eliminated terms may not be exact zeros for floating-point coefficients
+        rem.truncate(d - 1);
+        (Self::from(quot), Self::from(rem))
+    }
+
+    /// Calculate the monic greatest common divisor of two polynomials, which is exact for `Fraction` coefficients.
+    pub fn gcd(a: &Self, b: &Self) -> Self {
+        let (mut a, mut b) = (a.clone(), b.clone());
+        while !b.is_zero() {
+            let r = &a % &b;
+            a = b;
+            b = r;
+        }
+        a.monic()
+    }
+}
+
+// Find all the roots of a polynomial with the nonzero constant term by the Aberth-Ehrlich method.
+fn aberth(coeffs: &[f64]) -> Vec<Complex> {
+    let n = coeffs.len() - 1;
+    let lead = coeffs[n];
+    if n == 1 {
+        return vec![Complex::from(-coeffs[0] / lead)];
+    }
+
+    // p(z) and p'(z) by Horner's method
+    let eval = |z: Complex| {
+        let (mut p, mut dp) = (Complex::ZERO, Complex::ZERO);
+        for &c in coeffs.iter().rev() {
+            dp = dp * z + p;
+            p = p * z + c;
+        }
+        (p, dp)
+    };
+
+    // start on a circle of the geometric mean radius of the roots, the offset breaks the symmetry
+    let radius = (coeffs[0] / lead).abs().powf(1. / n as f64);
+    let mut z: Vec<Complex> = (0..n)
+        .map(|k| Complex::from_polar(radius, 2. * std::f64::consts::PI * k as f64 / n as f64 + 0.4))
+        .collect();
+
+    for _ in 0..100 * n {
+        let mut converged = true;
+        for k in 0..n {
+            let (p, dp) = eval(z[k]);
+            if p == Complex::ZERO {
+                continue;
+            }
+
+            let ratio = p / dp;
+            let repulsion = (0..n).filter(|&j| j != k).fold(Complex::ZERO, |sum, j| sum + Complex::ONE / (z[k] - z[j]));
+            let step = ratio / (Complex::ONE - ratio * repulsion);
+            if step.is_finite() {
+                z[k] -= step;
+                converged &= step.abs() <= 4. * f64::EPSILON * z[k].abs();
+            }
+        }
+
+        if converged {
+            break;
+        }
+    }
+    z
+}
+
+/*
+Construct
+*/
+
+impl<T: Number, const N: usize> From<[T; N]> for Poly<T> {
+    fn from(value: [T; N]) -> Self {
+        Self { coeffs: Vec::from(value) }.normalize()
+    }
+}
+
+impl<T: Number> From<Vec<T>> for Poly<T> {
+    fn from(value: Vec<T>) -> Self {
+        Self { coeffs: value }.normalize()
+    }
+}
+
+impl<T: Number> From<List<T>> for Poly<T> {
+    fn from(value: List<T>) -> Self {
+        Self::from(value.data)
+    }
+}
+
+/*
+Function
+*/
+
+impl<T: Number> Neg for Poly<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            coeffs: self.coeffs.into_iter().map(|c| -c).collect(),
+        }
+    }
+}
+
+#[auto_impl_ops::auto_ops]
+impl<T: Number> Add for Poly<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (mut long, short) = if self.coeffs.len() >= rhs.coeffs.len() { (self, rhs) } else { (rhs, self) };
+        for (a, b) in long.coeffs.iter_mut().zip(short.coeffs) {
+            *a = a.clone() + b;
+        }
+        long.normalize()
+    }
+}
+
+#[auto_impl_ops::auto_ops]
+impl<T: Number> Sub for Poly<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+#[auto_impl_ops::auto_ops]
+impl<T: Number> Mul for Poly<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.is_zero() || rhs.is_zero() {
+            return Self::new();
+        }
+
+        let mut coeffs = vec![T::default(); self.coeffs.len() + rhs.coeffs.len() - 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (j, b) in rhs.coeffs.iter().enumerate() {
+                coeffs[i + j] = coeffs[i + j].clone() + a.clone() * b.clone();
+            }
+        }
+        Self { coeffs }.normalize()
+    }
+}
+
+#[auto_impl_ops::auto_ops]
+impl<T: Field> Div for Poly<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.divmod(&rhs).0
+    }
+}
+
+#[auto_impl_ops::auto_ops]
+impl<T: Field> Rem for Poly<T> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.divmod(&rhs).1
+    }
+}
+
+/*
+Display
+*/
+
+impl<T: Number + PartialOrd + Display> Display for Poly<T> {
+    /// Format in descending order of degree, e.g. `x^2 - 3*x + 2`.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        for (i, c) in self.coeffs.iter().enumerate().rev().filter(|(_, c)| **c != T::default()) {
+            let negative = *c < T::default();
+            let abs = if negative { -c.clone() } else { c.clone() };
+            match (i + 1 == self.coeffs.len(), negative) {
+                (true, true) => write!(f, "-")?,
+                (true, false) => {}
+                (false, true) => write!(f, " - ")?,
+                (false, false) => write!(f, " + ")?,
+            }
+
+            match i {
+                0 => write!(f, "{abs}")?,
+                _ if abs == T::from(1) => {}
+                _ => write!(f, "{abs}*")?,
+            }
+            match i {
+                0 => {}
+                1 => write!(f, "x")?,
+                _ => write!(f, "x^{i}")?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,313 @@
+use std::collections::HashSet;
+
+use pyinrs::{BigDecimal, Context, Int, ParseBigDecimalError, Rounding, Signal, Signals};
+use rstest::{fixture, rstest};
+
+struct Fixture {
+    zero: BigDecimal,
+    positive: BigDecimal,
+    negative: BigDecimal,
+}
+
+#[fixture]
+fn setup() -> Fixture {
+    Fixture {
+        zero: BigDecimal::new(),
+        positive: BigDecimal::from("1.30"),
+        negative: BigDecimal::from("-1.20"),
+    }
+}
+
+fn context(prec: usize, rounding: Rounding) -> Context {
+    Context {
+        prec,
+        rounding,
+        ..Context::default()
+    }
+}
+
+#[rstest]
+#[case::no_panic(1)]
+#[should_panic(expected = "Error: Wrong decimal literal.")]
+#[case::panic_with_message(2)]
+fn basics(#[case] case: i32) {
+    match case {
+        1 => {
+            assert_eq!(BigDecimal::new(), BigDecimal::from(0));
+            assert_eq!(BigDecimal::from(Int::from(-7)), BigDecimal::from("-7"));
+            assert_eq!(BigDecimal::from(&Int::from(42)).to_string(), "42");
+            assert_eq!(BigDecimal::from(12345u64).to_string(), "12345");
+            assert_eq!(BigDecimal::from("1.30").to_string(), "1.30");
+        }
+        2 => {
+            let _ = BigDecimal::from("1.2.3");
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[rstest]
+fn compare(setup: Fixture) {
+    assert!(setup.zero == BigDecimal::from("-0E+2"));
+    assert!(setup.positive == BigDecimal::from("1.3"));
+    assert!(setup.positive != setup.negative);
+    assert!(setup.negative < setup.zero);
+    assert!(setup.zero < setup.positive);
+    assert!(BigDecimal::from("1e50") > BigDecimal::from("9.99e49"));
+    assert!(BigDecimal::from("-1e50") < BigDecimal::from("-9.99e49"));
+
+    let set = HashSet::from([BigDecimal::from("1.0"), BigDecimal::from("1.00"), BigDecimal::from("100E-2")]);
+    assert_eq!(set.len(), 1);
+}
+
+#[rstest]
+fn examination(setup: Fixture) {
+    assert_eq!(setup.positive.coefficient(), &Int::from(130));
+    assert_eq!(setup.positive.exponent(), -2);
+    assert_eq!(setup.positive.adjusted(), 0);
+    assert_eq!(BigDecimal::from("123E+5").adjusted(), 7);
+
+    assert!(setup.zero.is_zero());
+    assert!(setup.negative.is_negative());
+    assert!(!setup.positive.is_negative());
+
+    // -0 has the sign bit but is not negative
+    assert!(BigDecimal::from("-0E+2").is_signed());
+    assert!(!BigDecimal::from("-0E+2").is_negative());
+    assert!(setup.negative.is_signed());
+    assert!(!setup.zero.is_signed());
+}
+
+#[rstest]
+fn unary(setup: Fixture) {
+    assert_eq!((-&setup.positive).to_string(), "-1.30");
+    assert_eq!(setup.negative.abs().to_string(), "1.20");
+    assert_eq!(Context::local(context(5, Rounding::HalfUp), || -BigDecimal::from("1.234567")).to_string(), "-1.2346");
+
+    // the sign of a zero is dropped, unless rounding toward negative infinity
+    let negative_zero = BigDecimal::from("-0");
+    assert_eq!((-&setup.zero).to_string(), "0");
+    assert_eq!((-&negative_zero).to_string(), "0");
+    assert_eq!(negative_zero.abs().to_string(), "0");
+    assert_eq!(negative_zero.plus().to_string(), "0");
+    Context::local(context(28, Rounding::Floor), || {
+        assert_eq!((-&setup.zero).to_string(), "-0");
+        assert_eq!(negative_zero.plus().to_string(), "-0");
+    });
+}
+
+#[rstest]
+fn signed_zero(setup: Fixture) {
+    let negative_zero = BigDecimal::from("-0");
+    assert_eq!((&negative_zero + &setup.zero).to_string(), "0");
+    assert_eq!((&negative_zero + &negative_zero).to_string(), "-0");
+    assert_eq!((&setup.positive - &setup.positive).to_string(), "0.00");
+    assert_eq!((&negative_zero * BigDecimal::from(5)).to_string(), "-0");
+    assert_eq!((BigDecimal::from(-4) % BigDecimal::from(2)).to_string(), "-0");
+    assert_eq!((&setup.zero / &BigDecimal::from("-1.30")).to_string(), "-0E+2");
+
+    Context::local(context(28, Rounding::Floor), || {
+        assert_eq!((&negative_zero + &setup.zero).to_string(), "-0");
+        assert_eq!((&setup.positive - &setup.positive).to_string(), "-0.00");
+    });
+}
+
+#[rstest]
+fn add(setup: Fixture) {
+    assert_eq!((&setup.positive + &BigDecimal::from("1.20")).to_string(), "2.50");
+    assert_eq!((&setup.positive + &setup.negative).to_string(), "0.10");
+    assert_eq!((BigDecimal::from("1.0") - BigDecimal::from("1.0")).to_string(), "0.0");
+    assert_eq!(
+        (BigDecimal::from("1e50") + BigDecimal::from("1e-50")).to_string(),
+        "1.000000000000000000000000000E+50"
+    );
+    assert_eq!(
+        (BigDecimal::from("1e50") - BigDecimal::from("1e-50")).to_string(),
+        "1.000000000000000000000000000E+50"
+    );
+
+    let mut x = setup.zero;
+    x += &setup.positive;
+    x -= &setup.negative;
+    assert_eq!(x.to_string(), "2.50");
+}
+
+#[rstest]
+fn mul(setup: Fixture) {
+    assert_eq!((&setup.positive * &BigDecimal::from("1.20")).to_string(), "1.5600");
+    assert_eq!((&setup.positive * &setup.negative).to_string(), "-1.5600");
+    assert_eq!(
+        (BigDecimal::from("1234567890123456789012345678901234567890") * BigDecimal::from(1)).to_string(),
+        "1.234567890123456789012345679E+39"
+    );
+}
+
+#[rstest]
+#[case::no_panic(1)]
+#[should_panic(expected = "Error: Divide by zero.")]
+#[case::panic_with_message(2)]
+#[should_panic(expected = "Error: Divide by zero.")]
+#[case::panic_with_message(3)]
+fn div(setup: Fixture, #[case] case: i32) {
+    match case {
+        1 => {
+            assert_eq!((BigDecimal::from(1) / BigDecimal::from(3)).to_string(), "0.3333333333333333333333333333");
+            assert_eq!((BigDecimal::from(2) / BigDecimal::from(3)).to_string(), "0.6666666666666666666666666667");
+            assert_eq!((BigDecimal::from(-2) / BigDecimal::from(3)).to_string(), "-0.6666666666666666666666666667");
+            assert_eq!((BigDecimal::from(1) / BigDecimal::from(4)).to_string(), "0.25");
+            assert_eq!((BigDecimal::from("1.00") / BigDecimal::from(4)).to_string(), "0.25");
+            assert_eq!((BigDecimal::from(100) / BigDecimal::from("0.1")).to_string(), "1.00E+3");
+            assert_eq!((BigDecimal::from(12) / BigDecimal::from(12)).to_string(), "1");
+            assert_eq!((&setup.zero / &setup.positive).to_string(), "0E+2");
+        }
+        2 => {
+            let _ = setup.positive / setup.zero;
+        }
+        3 => {
+            let _ = setup.positive % setup.zero;
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[rstest]
+#[case::no_panic(1)]
+#[should_panic(expected = "Error: Division impossible.")]
+#[case::panic_with_message(2)]
+#[should_panic(expected = "Error: Division impossible.")]
+#[case::panic_with_message(3)]
+fn rem(#[case] case: i32) {
+    match case {
+        1 => {
+            assert_eq!((BigDecimal::from(7) % BigDecimal::from(3)).to_string(), "1");
+            assert_eq!((BigDecimal::from(-7) % BigDecimal::from(3)).to_string(), "-1");
+            assert_eq!((BigDecimal::from("7.5") % BigDecimal::from(2)).to_string(), "1.5");
+
+            // the integer quotient fits in the precision
+            Context::local(context(2, Rounding::HalfEven), || {
+                assert_eq!((BigDecimal::from(99) % BigDecimal::from(1)).to_string(), "0");
+                assert_eq!((BigDecimal::from("1e3") % BigDecimal::from(11)).to_string(), "10");
+            });
+        }
+        2 => _ = BigDecimal::from("1e1000") % BigDecimal::from(7),
+        3 => Context::local(context(2, Rounding::HalfEven), || {
+            _ = BigDecimal::from(100) % BigDecimal::from(1);
+        }),
+        _ => unreachable!(),
+    }
+}
+
+#[rstest]
+fn context_rounding() {
+    let cases = ["1.25", "1.35", "-1.25", "1.21", "-1.29", "1.01", "1.51"];
+    let expected = [
+        (Rounding::Ceiling, ["1.3", "1.4", "-1.2", "1.3", "-1.2", "1.1", "1.6"]),
+        (Rounding::Down, ["1.2", "1.3", "-1.2", "1.2", "-1.2", "1.0", "1.5"]),
+        (Rounding::Floor, ["1.2", "1.3", "-1.3", "1.2", "-1.3", "1.0", "1.5"]),
+        (Rounding::HalfDown, ["1.2", "1.3", "-1.2", "1.2", "-1.3", "1.0", "1.5"]),
+        (Rounding::HalfEven, ["1.2", "1.4", "-1.2", "1.2", "-1.3", "1.0", "1.5"]),
+        (Rounding::HalfUp, ["1.3", "1.4", "-1.3", "1.2", "-1.3", "1.0", "1.5"]),
+        (Rounding::Up, ["1.3", "1.4", "-1.3", "1.3", "-1.3", "1.1", "1.6"]),
+        (Rounding::Up05, ["1.2", "1.3", "-1.2", "1.2", "-1.2", "1.1", "1.6"]),
+    ];
+
+    for (rounding, results) in expected {
+        Context::local(context(2, rounding), || {
+            for (case, result) in cases.iter().zip(results) {
+                assert_eq!(BigDecimal::from(*case).plus().to_string(), result, "{rounding:?} {case}");
+            }
+        });
+    }
+
+    // discarding only zeros is exact, so rounding up does not increase the coefficient
+    Context::local(context(2, Rounding::Up), || {
+        assert_eq!(BigDecimal::from(100).plus().to_string(), "1.0E+2");
+        assert_eq!(BigDecimal::from(101).plus().to_string(), "1.1E+2");
+    });
+}
+
+#[rstest]
+fn context_scope() {
+    assert_eq!(Context::current(), Context::default());
+
+    Context::local(context(5, Rounding::HalfUp), || {
+        assert_eq!(BigDecimal::from("2.25").plus().to_string(), "2.25");
+        assert_eq!((BigDecimal::from(1) / BigDecimal::from(7)).to_string(), "0.14286");
+        assert_eq!(BigDecimal::from("99999.5").plus().to_string(), "1.0000E+5");
+    });
+    assert_eq!(Context::current(), Context::default());
+
+    Context::set(Context {
+        prec: 3,
+        emin: -5,
+        emax: 5,
+        ..Context::default()
+    });
+    assert_eq!(BigDecimal::from("1e-7").plus().to_string(), "1E-7");
+    assert_eq!(BigDecimal::from("1.234e-6").plus().to_string(), "0.0000012");
+    assert_eq!(BigDecimal::from("1e-10").plus().to_string(), "0E-7");
+}
+
+#[rstest]
+#[should_panic(expected = "Error: Decimal overflow.")]
+fn overflow() {
+    Context::set(Context { emax: 5, ..Context::default() });
+    let _ = BigDecimal::from("1e5") * BigDecimal::from(10);
+}
+
+#[rstest]
+fn signals() {
+    Context::set(context(3, Rounding::Ceiling));
+    assert_eq!(BigDecimal::from("1.230").plus().to_string(), "1.23");
+    assert!(Context::current().flags.contains(Signal::Rounded));
+    assert!(!Context::current().flags.contains(Signal::Inexact));
+
+    assert_eq!(BigDecimal::from("1.231").plus().to_string(), "1.24");
+    assert!(Context::current().flags.contains(Signal::Inexact));
+
+    Context::clear_flags();
+    Context::set(Context {
+        emax: 5,
+        traps: Signals::new(),
+        ..Context::current()
+    });
+    assert_eq!((BigDecimal::from("1e5") * BigDecimal::from(10)).to_string(), "9.99E+5");
+    assert!(Context::current().flags.contains(Signal::Overflow));
+}
+
+#[rstest]
+#[should_panic(expected = "Error: Require prec >= 1 and emin <= 0 <= emax for context.")]
+fn bad_context() {
+    Context::set(context(0, Rounding::HalfEven));
+}
+
+#[rstest]
+fn format(setup: Fixture) {
+    assert_eq!(setup.zero.to_string(), "0");
+    assert_eq!(setup.positive.to_string(), "1.30");
+    assert_eq!(setup.negative.to_string(), "-1.20");
+
+    assert_eq!(BigDecimal::from("123E+5").to_string(), "1.23E+7");
+    assert_eq!(BigDecimal::from("0.000001").to_string(), "0.000001");
+    assert_eq!(BigDecimal::from("0.0000001").to_string(), "1E-7");
+    assert_eq!(BigDecimal::from("-0E+2").to_string(), "-0E+2");
+    assert_eq!(BigDecimal::from("-12.345e-10").to_string(), "-1.2345E-9");
+    assert_eq!(
+        BigDecimal::from(Int::from("123456789012345678901234567890")).to_string(),
+        "123456789012345678901234567890"
+    );
+}
+
+#[rstest]
+fn parse() {
+    assert_eq!("1.5".parse::<BigDecimal>().unwrap().to_string(), "1.5");
+    assert_eq!(" +.5 ".parse::<BigDecimal>().unwrap().to_string(), "0.5");
+    assert_eq!("5.".parse::<BigDecimal>().unwrap().to_string(), "5");
+    assert_eq!("-2E3".parse::<BigDecimal>().unwrap().to_string(), "-2E+3");
+
+    assert_eq!("".parse::<BigDecimal>().unwrap_err(), ParseBigDecimalError);
+    assert!(".".parse::<BigDecimal>().is_err());
+    assert!("1e".parse::<BigDecimal>().is_err());
+    assert!("--1".parse::<BigDecimal>().is_err());
+    assert!("1_0".parse::<BigDecimal>().is_err());
+}
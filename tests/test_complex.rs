@@ -1,4 +1,7 @@
-use pyinrs::Complex;
+use std::collections::HashSet;
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use pyinrs::{Complex, ConversionError, Decimal, Fraction, Int};
 use rstest::{fixture, rstest};
 
 struct Fixture {
@@ -23,6 +26,37 @@ fn compare(setup: Fixture) {
 
     assert!(setup.zero != setup.positive);
     assert!(setup.positive != setup.negative);
+
+    assert!(Complex::from((0., -0.)) == Complex::from((-0., 0.)));
+    assert!(Complex::NAN != Complex::NAN);
+    assert!(Complex::from(1e20) != Complex::from(1e20 + 1e5));
+    assert!(Complex::from(0.1 + 0.2) != Complex::from(0.3));
+
+    let set = HashSet::from([Complex::from((0., -0.)), Complex::from((-0., 0.)), Complex::new()]);
+    assert_eq!(set.len(), 1);
+}
+
+#[rstest]
+#[case::no_panic(1)]
+#[should_panic(expected = "Error: Require non-negative tolerances.")]
+#[case::panic_with_message(2)]
+fn is_close(setup: Fixture, #[case] case: i32) {
+    match case {
+        1 => {
+            assert!(Complex::from(0.1 + 0.2).is_close(&Complex::from(0.3), 1e-9, 0.));
+            assert!(setup.positive.is_close(&Complex::from((1. + 1e-10, 2.)), 1e-9, 0.));
+            assert!(!setup.positive.is_close(&Complex::from((1. + 1e-8, 2.)), 1e-9, 0.));
+            assert!(!setup.zero.is_close(&Complex::from(1e-10), 1e-9, 0.));
+            assert!(setup.zero.is_close(&Complex::from(1e-10), 1e-9, 1e-9));
+            assert!(Complex::INFINITY.is_close(&Complex::INFINITY, 1e-9, 0.));
+            assert!(!Complex::INFINITY.is_close(&Complex::from(1e308), 1e-9, 0.));
+            assert!(!Complex::NAN.is_close(&Complex::NAN, 1e-9, 0.));
+        }
+        2 => {
+            let _ = setup.positive.is_close(&setup.negative, -1., 0.);
+        }
+        _ => unreachable!(),
+    }
 }
 
 #[rstest]
@@ -51,6 +85,42 @@ fn examination(setup: Fixture) {
     assert_eq!(setup.negative.arg(), 2.0344439357957027);
 }
 
+#[rstest]
+fn polar(setup: Fixture) {
+    assert_eq!(Complex::from((3., 4.)).polar(), (5., 0.9272952180016122));
+    assert_eq!(Complex::from((-1., -0.)).polar(), (1., -PI));
+    assert_eq!(setup.negative.phase(), setup.negative.arg());
+
+    assert_eq!(Complex::from_polar(2., 0.), Complex::from(2.));
+    assert_eq!(Complex::rect(1., PI), Complex::from((-1., 1.2246467991473532e-16)));
+    assert_eq!(Complex::rect(5., 0.9272952180016122), Complex::from((3.0000000000000004, 3.9999999999999996)));
+    assert!(Complex::rect(-2., 0.).imag().is_sign_negative());
+    let z = Complex::rect(f64::NEG_INFINITY, 2.);
+    assert_eq!((z.real(), z.imag()), (f64::INFINITY, f64::NEG_INFINITY));
+    assert_eq!(Complex::rect(0., f64::INFINITY), Complex::ZERO);
+}
+
+#[rstest]
+#[should_panic(expected = "Error: Math domain error.")]
+fn bad_polar() {
+    let _ = Complex::rect(1., f64::INFINITY);
+}
+
+#[rstest]
+fn constants() {
+    assert_eq!(Complex::I * Complex::I, -Complex::ONE);
+    assert_eq!(Complex::ZERO, Complex::new());
+    assert_eq!(Complex::ZERO, Complex::default());
+    assert_eq!(Complex::ONE + Complex::I, Complex::from((1., 1.)));
+
+    assert!(Complex::ONE.is_finite());
+    assert!(!Complex::INFINITY.is_finite());
+    assert!(Complex::INFINITY.is_infinite());
+    assert!(Complex::from((f64::NAN, f64::INFINITY)).is_infinite());
+    assert!(Complex::NAN.is_nan());
+    assert!(!Complex::I.is_nan());
+}
+
 #[rstest]
 fn unary(setup: Fixture) {
     assert_eq!(-setup.zero, Complex::new());
@@ -130,6 +200,9 @@ fn div(setup: Fixture, #[case] case: i32) {
             // 4
             assert_eq!(setup.zero / setup.negative, Complex::from(0.));
 
+            let z = Complex::ONE / Complex::NAN;
+            assert!(z.real().is_nan() && z.imag().is_nan());
+
             Complex::new() // for compatible types
         }
         2 => setup.positive / setup.zero,
@@ -142,24 +215,12 @@ fn div(setup: Fixture, #[case] case: i32) {
 #[rstest]
 fn pow(setup: Fixture) {
     assert_eq!(Complex::pow(&setup.positive, &setup.zero), Complex::from(1.));
-    assert_eq!(
-        Complex::pow(&setup.positive, &setup.positive),
-        Complex::from((-0.22251715680177267, 0.10070913113607541))
-    );
-    assert_eq!(
-        Complex::pow(&setup.positive, &setup.negative),
-        Complex::from((0.04281551979798478, 0.023517649351954585))
-    );
+    assert!(Complex::pow(&setup.positive, &setup.positive).is_close(&Complex::from((-0.22251715680177267, 0.10070913113607541)), 1e-15, 0.));
+    assert!(Complex::pow(&setup.positive, &setup.negative).is_close(&Complex::from((0.04281551979798478, 0.023517649351954585)), 1e-15, 0.));
 
     assert_eq!(Complex::pow(&setup.negative, &setup.zero), Complex::from(1.));
-    assert_eq!(
-        Complex::pow(&setup.negative, &setup.positive),
-        Complex::from((-0.0335067906880002, -0.018404563532749985))
-    );
-    assert_eq!(
-        Complex::pow(&setup.negative, &setup.negative),
-        Complex::from((0.006965545047800022, -0.0031525388861500334))
-    );
+    assert!(Complex::pow(&setup.negative, &setup.positive).is_close(&Complex::from((-0.0335067906880002, -0.018404563532749985)), 1e-15, 0.));
+    assert!(Complex::pow(&setup.negative, &setup.negative).is_close(&Complex::from((0.006965545047800022, -0.0031525388861500334)), 1e-15, 0.));
 
     assert_eq!(Complex::pow(&setup.zero, &setup.zero), Complex::from(1.));
 }
@@ -170,13 +231,135 @@ fn bad_pow(setup: Fixture) {
     let _ = Complex::pow(&setup.zero, &setup.positive);
 }
 
+#[rstest]
+fn cmath(setup: Fixture) {
+    let z = setup.positive;
+    assert_eq!(z.exp(), Complex::from((-1.1312043837568135, 2.4717266720048188)));
+    assert_eq!(z.log(None), Complex::from((0.8047189562170503, 1.1071487177940904)));
+    assert_eq!(z.log(Some(Complex::from(2.))), Complex::from((1.1609640474436813, 1.5972779646881088)));
+    assert_eq!(z.log10(), Complex::from((0.3494850021680094, 0.480828578784234)));
+    assert_eq!(z.sqrt(), Complex::from((1.272019649514069, 0.7861513777574233)));
+
+    assert_eq!(z.sin(), Complex::from((3.165778513216168, 1.9596010414216063)));
+    assert_eq!(z.cos(), Complex::from((2.0327230070196656, -3.0518977991518)));
+    assert_eq!(z.tan(), Complex::from((0.0338128260798967, 1.0147936161466335)));
+    assert_eq!(z.asin(), Complex::from((0.4270785863924761, 1.5285709194809982)));
+    assert_eq!(z.acos(), Complex::from((1.1437177404024204, -1.5285709194809982)));
+    assert_eq!(z.atan(), Complex::from((1.3389725222944935, 0.40235947810852507)));
+
+    assert_eq!(z.sinh(), Complex::from((-0.4890562590412937, 1.4031192506220405)));
+    assert_eq!(z.cosh(), Complex::from((-0.64214812471552, 1.0686074213827783)));
+    assert_eq!(z.tanh(), Complex::from((1.16673625724092, -0.24345820118572534)));
+    assert_eq!(z.asinh(), Complex::from((1.4693517443681852, 1.063440023577752)));
+    assert_eq!(z.acosh(), Complex::from((1.5285709194809982, 1.1437177404024204)));
+    assert_eq!(z.atanh(), Complex::from((0.17328679513998632, 1.1780972450961724)));
+}
+
+#[rstest]
+fn branch_cut() {
+    // the sign of zero selects the side of the branch cut
+    assert_eq!(Complex::from((-4., 0.)).sqrt().imag(), 2.);
+    assert_eq!(Complex::from((-4., -0.)).sqrt().imag(), -2.);
+    assert_eq!(Complex::from((-1., 0.)).log(None).imag(), PI);
+    assert_eq!(Complex::from((-1., -0.)).log(None).imag(), -PI);
+    assert_eq!(Complex::from((2., 0.)).asin(), Complex::from((FRAC_PI_2, 1.3169578969248166)));
+    assert_eq!(Complex::from((2., -0.)).asin(), Complex::from((FRAC_PI_2, -1.3169578969248166)));
+    assert_eq!(Complex::from((0., 2.)).atan(), Complex::from((FRAC_PI_2, 0.5493061443340549)));
+    assert_eq!(Complex::from((-0., 2.)).atan(), Complex::from((-FRAC_PI_2, 0.5493061443340549)));
+
+    // signed zeros are kept
+    assert!(Complex::from((0., -0.)).sqrt().imag().is_sign_negative());
+    assert!(Complex::from((-0., 0.)).sinh().real().is_sign_negative());
+    assert!(Complex::from((1., -0.)).exp().imag().is_sign_negative());
+    assert!(Complex::from((0.5, -0.)).atanh().imag().is_sign_negative());
+
+    // huge values do not overflow
+    assert_eq!(Complex::from((1e300, 0.)).acosh(), Complex::from((691.4686750787736, 0.)));
+    assert_eq!(
+        Complex::from((1e-310, 1e-310)).sqrt(),
+        Complex::from((1.0986841134678082e-155, 4.550898605622267e-156))
+    );
+}
+
+#[rstest]
+#[should_panic(expected = "Error: Math domain error.")]
+#[case::panic_with_message(1)]
+#[should_panic(expected = "Error: Math domain error.")]
+#[case::panic_with_message(2)]
+#[should_panic(expected = "Error: Math domain error.")]
+#[case::panic_with_message(3)]
+fn bad_cmath(setup: Fixture, #[case] case: i32) {
+    match case {
+        1 => _ = setup.zero.log(None),
+        2 => _ = Complex::from(1.).atanh(),
+        3 => _ = Complex::from((0., -1.)).atan(),
+        _ => unreachable!(),
+    }
+}
+
+#[rstest]
+fn numeric_tower(setup: Fixture) {
+    assert_eq!(Complex::from(Fraction::from((1, 4))), Complex::from(0.25));
+    assert_eq!(Complex::from(Decimal::from("-1.5")), Complex::from(-1.5));
+    assert_eq!(Complex::from(Int::from(3)), Complex::from(3.));
+
+    assert_eq!(f64::try_from(Complex::from(2.5)), Ok(2.5));
+    assert_eq!(f64::try_from(setup.positive), Err(ConversionError::NotReal));
+    assert_eq!(Fraction::try_from(Complex::from(0.75)), Ok(Fraction::from((3, 4))));
+    assert_eq!(Fraction::try_from(Complex::from(f64::NAN)), Err(ConversionError::NotFinite));
+    assert_eq!(Decimal::try_from(Complex::from(0.5)), Ok(Decimal::from("0.5")));
+    assert_eq!(Decimal::try_from(setup.negative), Err(ConversionError::NotReal));
+
+    assert_eq!(Decimal::from("0.5") + setup.positive, Complex::from((1.5, 2.)));
+    assert_eq!(setup.positive * Fraction::from((1, 2)), Complex::from((0.5, 1.)));
+    assert_eq!(setup.negative - Int::from(1), Complex::from((-2., 2.)));
+    assert_eq!(&Int::from(2) / setup.positive, Complex::from((0.4, -0.8)));
+
+    let mut c = setup.positive;
+    c /= Decimal::from(2);
+    assert_eq!(c, Complex::from((0.5, 1.)));
+
+    assert_eq!(Complex::from(3), Complex::from(3.));
+    assert_eq!(Complex::from(1.5f32), Complex::from(1.5));
+    assert_eq!(setup.positive * 2., Complex::from((2., 4.)));
+    assert_eq!(2 * setup.positive, Complex::from((2., 4.)));
+    assert_eq!(setup.positive + 1u8, Complex::from((2., 2.)));
+    assert_eq!(1.5f32 - setup.positive, Complex::from((0.5, -2.)));
+    assert_eq!(5usize / setup.positive, Complex::from((1., -2.)));
+    assert_eq!(setup.negative / 2i64, Complex::from((-0.5, 1.)));
+
+    c += 1;
+    c *= 2.;
+    c -= 1u32;
+    assert_eq!(c, Complex::from((2., 2.)));
+}
+
 #[rstest]
 fn format(setup: Fixture) {
-    assert_eq!(format!("{}", setup.zero), "(0+0j)");
+    assert_eq!(format!("{}", setup.zero), "0j");
     assert_eq!(format!("{}", setup.positive), "(1+2j)");
     assert_eq!(format!("{}", setup.negative), "(-1+2j)");
 
     assert_eq!(format!("{}", Complex::from((1., -2.))), "(1-2j)");
+    assert_eq!(format!("{}", Complex::from((0., 2.))), "2j");
+    assert_eq!(format!("{}", Complex::from((0., -0.))), "-0j");
+    assert_eq!(format!("{}", Complex::from((-0., 0.))), "(-0+0j)");
+    assert_eq!(format!("{}", Complex::from((1., -0.))), "(1-0j)");
+    assert_eq!(format!("{}", Complex::from((f64::NAN, f64::INFINITY))), "(nan+infj)");
+    assert_eq!(format!("{}", Complex::from((1e16, 1e-5))), "(1e+16+1e-05j)");
+    assert_eq!(format!("{}", Complex::from((0.1, 1. / 3.))), "(0.1+0.3333333333333333j)");
+
+    assert_eq!(format!("{:.3}", Complex::from((1. / 3., -2. / 3.))), "(0.333-0.667j)");
+    assert_eq!(format!("{:.2}", Complex::from((12345., 0.5))), "(1.2e+04+0.5j)");
+    assert_eq!(format!("{:+}", setup.positive), "(+1+2j)");
+    assert_eq!(format!("{:+}", Complex::I), "+1j");
+    assert_eq!(format!("{:10}", setup.positive), "    (1+2j)");
+    assert_eq!(format!("{:*<10}", setup.positive), "(1+2j)****");
+    assert_eq!(format!("{:_^11.1}", Complex::from((1.25, 2.))), "__(1+2j)___");
+
+    for z in [setup.negative, Complex::from((-0., -0.)), Complex::from((1e300, -5e-324)), Complex::INFINITY] {
+        assert_eq!(z.to_string().parse::<Complex>().unwrap(), z);
+    }
 }
 
 #[rstest]
@@ -196,4 +379,85 @@ fn parse() {
     assert!("1+z2j".parse::<Complex>().is_err());
     assert!("1+2zj".parse::<Complex>().is_err());
     assert!("123jj".parse::<Complex>().is_err());
+
+    assert_eq!(Complex::from((1., 2.)), "(1+2j)".parse().unwrap());
+    assert_eq!(Complex::from((1., 2.)), " ( 1+2J ) ".parse().unwrap());
+    assert_eq!(Complex::from((1e3, -2.5e-3)), "1e3-2.5E-3j".parse().unwrap());
+    assert_eq!(Complex::from((1000., 0.5)), "1_000+.5j".parse().unwrap());
+    assert_eq!(Complex::from((0., -1.)), "-j".parse().unwrap());
+    assert_eq!(Complex::from((1., 1.)), "1+j".parse().unwrap());
+    assert_eq!(Complex::from((f64::INFINITY, f64::NEG_INFINITY)), "inf-Infinityj".parse().unwrap());
+    assert!("nan+nanj".parse::<Complex>().unwrap().is_nan());
+
+    let z = Complex::from((-1.5, 0.25));
+    assert_eq!(z, z.to_string().parse().unwrap());
+
+    assert!("".parse::<Complex>().is_err());
+    assert!("()".parse::<Complex>().is_err());
+    assert!("(1+2j".parse::<Complex>().is_err());
+    assert!("1+ 2j".parse::<Complex>().is_err());
+    assert!("1+-2j".parse::<Complex>().is_err());
+    assert!("2+3".parse::<Complex>().is_err());
+    assert!("1e".parse::<Complex>().is_err());
+    assert!("1__0".parse::<Complex>().is_err());
+    assert!("1j+2".parse::<Complex>().is_err());
+}
+
+#[rstest]
+fn exact() {
+    let half = |n: i128| Fraction::from((n, 2));
+    let a = Complex::from((half(1), half(3)));
+    let b = Complex::from((Fraction::from(1), Fraction::from(-1)));
+
+    assert_eq!(Complex::zero(), Complex::from((Fraction::new(), Fraction::new())));
+    assert_eq!(Complex::<Int>::default(), Complex::zero());
+    assert_eq!(a.real(), half(1));
+    assert_eq!(a.norm(), Fraction::from((5, 2)));
+    assert_eq!(a + b, Complex::from((half(3), half(1))));
+    assert_eq!(a - b, Complex::from((half(-1), half(5))));
+    assert_eq!(a * b, Complex::from((Fraction::from(2), Fraction::from(1))));
+    assert_eq!(a / b, Complex::from((half(-1), Fraction::from(1))));
+    assert_eq!(a / b * b, a);
+    assert_eq!(-a.conjugate(), Complex::from((half(-1), half(3))));
+
+    assert_eq!(a.to_string(), "(1/2+3/2j)");
+    assert_eq!(b.to_string(), "(1-1j)");
+
+    let set = HashSet::from([a, Complex::from((Fraction::from((2, 4)), Fraction::from((6, 4))))]);
+    assert_eq!(set.len(), 1);
+}
+
+#[rstest]
+#[case::no_panic(1)]
+#[should_panic(expected = "Error: Divide by zero.")]
+#[case::panic_with_message(2)]
+#[should_panic(expected = "Error: Divide by zero.")]
+#[case::panic_with_message(3)]
+fn gaussian(#[case] case: i32) {
+    let gauss = |real: i32, imag: i32| Complex::from((Int::from(real), Int::from(imag)));
+
+    match case {
+        1 => {
+            assert_eq!(gauss(7, 3).divmod(&gauss(2, 1)), (gauss(3, 0), gauss(1, 0)));
+            assert_eq!(gauss(-7, -3).divmod(&gauss(2, 1)), (gauss(-3, 0), gauss(-1, 0)));
+            assert_eq!(gauss(5, 0) / gauss(0, 2), gauss(0, -2));
+            assert_eq!(gauss(5, 0) % gauss(0, 2), gauss(1, 0));
+            assert_eq!(gauss(2, 1) * gauss(2, -1), gauss(5, 0));
+
+            assert_eq!(Complex::gcd(&gauss(11, 3), &gauss(1, 8)), gauss(2, 1));
+            assert_eq!(Complex::gcd(&gauss(4, 2), &gauss(6, 8)), gauss(4, 2));
+            assert_eq!(Complex::gcd(&gauss(0, -6), &gauss(4, 0)), gauss(2, 0));
+            assert_eq!(Complex::gcd(&gauss(3, 4), &gauss(0, 0)), gauss(3, 4));
+            assert_eq!(Complex::gcd(&gauss(0, 0), &gauss(0, 0)), gauss(0, 0));
+
+            assert_eq!(gauss(3, -4).to_string(), "(3-4j)");
+        }
+        2 => {
+            let _ = gauss(1, 2) / gauss(0, 0);
+        }
+        3 => {
+            let _ = Complex::from((Fraction::from(1), Fraction::new())) / Complex::zero();
+        }
+        _ => unreachable!(),
+    }
 }
@@ -1,4 +1,6 @@
-use pyinrs::Decimal;
+use std::{cmp::Ordering, collections::HashSet};
+
+use pyinrs::{Context, ConversionError, CyclicNotation, Decimal, Fraction, Int, ParseDecimalError, Rounding, Signal, Signals};
 use rstest::{fixture, rstest};
 
 struct Fixture {
@@ -92,6 +94,166 @@ fn rem(setup: Fixture) {
     assert_eq!(setup.zero % setup.negative, Decimal::from("0"));
 }
 
+#[rstest]
+fn rounding() {
+    assert_eq!(Decimal::from("2.5").floor(), Int::from(2));
+    assert_eq!(Decimal::from("-2.5").floor(), Int::from(-3));
+    assert_eq!(Decimal::from("2.5").ceil(), Int::from(3));
+    assert_eq!(Decimal::from("-2.5").ceil(), Int::from(-2));
+    assert_eq!(Decimal::from("2.7").trunc(), Int::from(2));
+    assert_eq!(Decimal::from("-2.7").trunc(), Int::from(-2));
+    assert_eq!(Decimal::from("2.5").round(), Int::from(2));
+    assert_eq!(Decimal::from("3.5").round(), Int::from(4));
+    assert_eq!(Decimal::from("-0.~6").round(), Int::from(-1));
+
+    assert_eq!(Decimal::from("2.675").round_to(2), Decimal::from("2.68"));
+    assert_eq!(Decimal::from("-0.125").round_to(2), Decimal::from("-0.12"));
+    assert_eq!(Decimal::from("0.~3").round_to(3), Decimal::from("0.333"));
+    assert_eq!(Decimal::from("1250").round_to(-2), Decimal::from("1200"));
+}
+
+#[rstest]
+fn quantize() {
+    assert_eq!(Decimal::from("2.675").quantize(2, Rounding::HalfUp), Decimal::from("2.68"));
+    assert_eq!(Decimal::from("-2.675").quantize(2, Rounding::HalfEven), Decimal::from("-2.68"));
+    assert_eq!(Decimal::from("2.665").quantize(2, Rounding::HalfEven), Decimal::from("2.66"));
+    assert_eq!(Decimal::from("2.665").quantize(2, Rounding::HalfDown), Decimal::from("2.66"));
+    assert_eq!(Decimal::from("-0.125").quantize(2, Rounding::Ceiling), Decimal::from("-0.12"));
+    assert_eq!(Decimal::from("-0.125").quantize(2, Rounding::Floor), Decimal::from("-0.13"));
+    assert_eq!(Decimal::from("0.~3").quantize(2, Rounding::Up), Decimal::from("0.34"));
+    assert_eq!(Decimal::from("0.~6").quantize(2, Rounding::Down), Decimal::from("0.66"));
+    assert_eq!(Decimal::from("1.005").quantize(2, Rounding::HalfUp), Decimal::from("1.01"));
+    assert_eq!(Decimal::from("1.01").quantize(1, Rounding::Up05), Decimal::from("1.1"));
+    assert_eq!(Decimal::from("1.21").quantize(1, Rounding::Up05), Decimal::from("1.2"));
+    assert_eq!(Decimal::from("7.5").quantize(0, Rounding::HalfEven), Decimal::from(8));
+    assert_eq!(Decimal::from("6.5").quantize(0, Rounding::HalfEven), Decimal::from(6));
+    assert_eq!(Decimal::from(1250).quantize(-2, Rounding::HalfUp), Decimal::from(1300));
+    assert_eq!(Decimal::from("0.25").quantize(40, Rounding::HalfUp), Decimal::from("0.25"));
+
+    assert_eq!(Decimal::from("1.5").scaleb(2), Decimal::from(150));
+    assert_eq!(Decimal::from("1.5").scaleb(-3), Decimal::from("0.0015"));
+    assert_eq!(Decimal::from(10i128.pow(30)).scaleb(-40), Decimal::from("0.0000000001"));
+    assert_eq!(Decimal::from("2e-38").scaleb(70), Decimal::from(2 * 10i128.pow(32)));
+    assert_eq!(Decimal::new().scaleb(i32::MAX), Decimal::new());
+
+    assert_eq!(Decimal::from("0.~3").normalize(), Decimal::from("0.3333333333333333333333333333"));
+    assert_eq!(Decimal::from("2.5").normalize(), Decimal::from("2.5"));
+    // the 39 decimal places do not fit, so only 38 are kept
+    assert_eq!(
+        Decimal::from("0.000000000001~3").normalize(),
+        Decimal::from("0.00000000000133333333333333333333333333")
+    );
+    Context::set(Context {
+        prec: 3,
+        rounding: Rounding::HalfUp,
+        ..Context::default()
+    });
+    assert_eq!(Decimal::from(123456).normalize(), Decimal::from(123000));
+    assert_eq!(Decimal::from("2.675").normalize(), Decimal::from("2.68"));
+    assert_eq!(Decimal::from("0.00012345").normalize(), Decimal::from("0.000123"));
+    assert_eq!(Decimal::from("999.5").normalize(), Decimal::from(1000));
+    assert_eq!(Decimal::new().normalize(), Decimal::new());
+}
+
+#[rstest]
+#[should_panic(expected = "Error: The result does not fit in a decimal.")]
+#[case::panic_with_message(1)]
+#[should_panic(expected = "Error: The result does not fit in a decimal.")]
+#[case::panic_with_message(2)]
+#[should_panic(expected = "Error: Invalid operation.")]
+#[case::panic_with_message(3)]
+fn bad_quantize(#[case] case: i32) {
+    match case {
+        1 => _ = Decimal::from("0.~3").quantize(40, Rounding::HalfEven),
+        2 => _ = Decimal::from(2).scaleb(40),
+        3 => _ = Decimal::INFINITY.quantize(2, Rounding::HalfEven),
+        _ => unreachable!(),
+    }
+}
+
+#[rstest]
+fn transcendental() {
+    let d = |s: &str| Decimal::from(s);
+
+    assert_eq!(d("2").sqrt(30), d("1.41421356237309504880168872421"));
+    assert_eq!(d("0.~4").sqrt(5), d("0.66667"));
+    assert_eq!(d("2.25").sqrt(1), d("2"));
+    assert_eq!(d("0").sqrt(3), d("0"));
+
+    assert_eq!(d("1").exp(30), d("2.71828182845904523536028747135"));
+    assert_eq!(d("-10").exp(20), d("0.000045399929762484851536"));
+    assert_eq!(d("50").exp(15), d("5184705528587070000000"));
+    assert_eq!(d("0").exp(5), d("1"));
+    // the 49 decimal places do not fit, so only 38 are kept
+    assert_eq!(d("-50").exp(28), d("0.00000000000000000000019287498479639178"));
+
+    assert_eq!(d("2").ln(30), d("0.693147180559945309417232121458"));
+    assert_eq!(d("1.000001").ln(20), d("0.00000099999950000033333308"));
+    assert_eq!(d("1").ln(5), d("0"));
+    assert_eq!(d("123.456").log10(25), d("2.0915122016277716810694"));
+    assert_eq!(d("0.001").log10(5), d("-3"));
+
+    assert_eq!(Decimal::pow(d("2"), d("0.5"), 20), d("1.4142135623730950488"));
+    assert_eq!(Decimal::pow(d("1.5"), d("-2.7"), 20), d("0.33462131420943865228"));
+    assert_eq!(Decimal::pow(d("-8"), d("0.~3"), 8), d("-2"));
+    assert_eq!(Decimal::pow(d("0.25"), d("-1.5"), 8), d("8"));
+    assert_eq!(Decimal::pow(d("0"), d("2.5"), 8), d("0"));
+
+    assert_eq!(d("1").sin(30), d("0.84147098480789650665250232163"));
+    assert_eq!(d("1").cos(30), d("0.540302305868139717400936607443"));
+    assert_eq!(d("1").tan(30), d("1.55740772465490223050697480746"));
+    assert_eq!(d("355").sin(20), d("-0.000030144353359488449214"));
+    assert_eq!(d("-100.5").cos(20), d("0.99952062532835145842"));
+    assert_eq!(d("1").atan(30), d("0.78539816339744830961566084582"));
+    assert_eq!(d("-7.5").atan(25), d("-1.438244794498222597961404"));
+    assert_eq!(d("0.001").atan(25), d("0.0009999996666668666665238096"));
+}
+
+#[rstest]
+#[case::no_panic(1)]
+#[should_panic(expected = "Error: Math domain error.")]
+#[case::panic_with_message(2)]
+#[should_panic(expected = "Error: Math domain error.")]
+#[case::panic_with_message(3)]
+#[should_panic(expected = "Error: Math domain error.")]
+#[case::panic_with_message(4)]
+#[should_panic(expected = "Error: The result does not fit in a decimal.")]
+#[case::panic_with_message(5)]
+#[should_panic(expected = "Error: Require digits >= 1.")]
+#[case::panic_with_message(6)]
+#[should_panic(expected = "Error: Invalid operation.")]
+#[case::panic_with_message(7)]
+fn bad_transcendental(#[case] case: i32) {
+    match case {
+        1 => assert_eq!(Decimal::from(-1).exp(3), Decimal::from("0.368")),
+        2 => _ = Decimal::from(-1).sqrt(3),
+        3 => _ = Decimal::from(-1).ln(3),
+        4 => _ = Decimal::pow(Decimal::from(-2), Decimal::from("0.5"), 3),
+        5 => _ = Decimal::from(1000).exp(3),
+        6 => _ = Decimal::from(2).sqrt(0),
+        7 => _ = Decimal::pow(Decimal::from(0), Decimal::from(0), 3),
+        _ => unreachable!(),
+    }
+}
+
+#[rstest]
+fn numeric_tower(setup: Fixture) {
+    assert_eq!(Decimal::try_from(Int::from(7)), Ok(Decimal::from(7)));
+    assert_eq!(Decimal::try_from(&Int::from("1".repeat(40).as_str())), Err(ConversionError::Overflow));
+    assert_eq!(Int::try_from(Decimal::from("12.0")), Ok(Int::from(12)));
+    assert_eq!(Int::try_from(setup.positive), Err(ConversionError::NotInteger));
+
+    assert_eq!(setup.positive + Int::from(1), Decimal::from("1.3"));
+    assert_eq!(&Int::from(10) * setup.negative, Decimal::from("-3"));
+    assert_eq!(setup.positive - Fraction::from((1, 3)), Decimal::from("-0.0~3"));
+    assert_eq!(Fraction::from((1, 2)) / setup.negative, Decimal::from("-1.~6"));
+
+    let mut d = setup.positive;
+    d -= Fraction::from((3, 10));
+    d += &Int::from(1);
+    assert_eq!(d, Decimal::from(1));
+}
+
 #[rstest]
 fn from_string() {
     assert_eq!(Decimal::from("0").as_fraction().to_string(), "0");
@@ -145,17 +307,130 @@ fn from_string() {
 #[rstest]
 fn to_string() {
     assert_eq!(Decimal::from("1.000").to_string(), "1");
-    assert_eq!(Decimal::from("0.~3").to_string(), "0.333...");
-    assert_eq!(Decimal::from("0.0~3").to_string(), "0.0333...");
-    assert_eq!(Decimal::from("0.83~3").to_string(), "0.8333...");
+    assert_eq!(Decimal::from("0.~3").to_string(), "0.~3");
+    assert_eq!(Decimal::from("0.0~3").to_string(), "0.0~3");
+    assert_eq!(Decimal::from("0.83~3").to_string(), "0.8~3");
     assert_eq!(Decimal::from("0.123").to_string(), "0.123");
-    assert_eq!(Decimal::from("0.~123").to_string(), "0.123123123...");
-    assert_eq!(Decimal::from("0.123~123").to_string(), "0.123123123...");
-    assert_eq!(Decimal::from("-0.~3").to_string(), "-0.333...");
-    assert_eq!(Decimal::from("-0.~1").to_string(), "-0.111...");
+    assert_eq!(Decimal::from("0.~123").to_string(), "0.~123");
+    assert_eq!(Decimal::from("0.123~123").to_string(), "0.~123");
+    assert_eq!(Decimal::from("-0.~3").to_string(), "-0.~3");
+    assert_eq!(Decimal::from("-0.~1").to_string(), "-0.~1");
+    assert_eq!(Decimal::from("12.34~56").to_string(), "12.34~56");
+    assert_eq!(Decimal::from("-7").to_string(), "-7");
 
     assert_eq!(Decimal::from("-0.0~0011#2").to_string(), "-0.1"); // -0.0001100110011...(2) = -0.1(10)
     assert_eq!(Decimal::from("0.~1#2").to_string(), "1"); // 0.111...(2) = 0.999...(10) = 1
+
+    // exact, not limited by f64
+    assert_eq!(
+        Decimal::from("123456789012345678901234567.123456789").to_string(),
+        "123456789012345678901234567.123456789"
+    );
+    assert_eq!((Decimal::from(1) / Decimal::from(7)).to_string(), "0.~142857");
+    assert_eq!((Decimal::from(1) / Decimal::from(97)).to_string().len(), 2 + 1 + 96);
+    assert_eq!(Decimal::from(pyinrs::Fraction::from((1, 1i128 << 100))).to_string().len(), 2 + 100);
+
+    // notations
+    let d = Decimal::from("12.34~56");
+    assert_eq!(d.to_cyclic_string(CyclicNotation::Tilde), "12.34~56");
+    assert_eq!(d.to_cyclic_string(CyclicNotation::Parentheses), "12.34(56)");
+    assert_eq!(d.to_cyclic_string(CyclicNotation::Overline), "12.345\u{305}6\u{305}");
+    assert_eq!(Decimal::from("0.5").to_cyclic_string(CyclicNotation::Parentheses), "0.5");
+
+    // round-trip
+    for s in ["0", "-1.5", "0.~3", "-12.34~56", "0.0~0011", "3.~142857"] {
+        let d = Decimal::from(s);
+        for notation in [CyclicNotation::Tilde, CyclicNotation::Parentheses, CyclicNotation::Overline] {
+            assert_eq!(Decimal::from(d.to_cyclic_string(notation).as_str()), d);
+        }
+    }
+    assert_eq!(Decimal::from("0.0(011)#2"), Decimal::from("0.0~011#2"));
+    assert_eq!(Decimal::from("0.13\u{305}#4"), Decimal::from("0.1~3#4"));
+    assert!("0.(3)4".parse::<Decimal>().is_err());
+    assert!("0.3\u{305}4".parse::<Decimal>().is_err());
+}
+
+#[rstest]
+fn to_sci_string(setup: Fixture) {
+    assert_eq!(setup.zero.to_sci_string(), "0");
+    assert_eq!(setup.negative.to_sci_string(), "-0.3");
+    assert_eq!(Decimal::from(1234500).to_sci_string(), "1.2345E+6");
+    assert_eq!(Decimal::from(100).to_sci_string(), "1E+2");
+    assert_eq!(Decimal::from("0.000001").to_sci_string(), "0.000001");
+    assert_eq!(Decimal::from("-0.00000012").to_sci_string(), "-1.2E-7");
+    assert_eq!(Decimal::from("0.1~6").to_sci_string(), "0.1666666666666666666666666667");
+    assert_eq!(Decimal::NEG_INFINITY.to_sci_string(), "-Infinity");
+
+    assert_eq!(Decimal::from(12345000).to_eng_string(), "12.345E+6");
+    assert_eq!(Decimal::from("1e-10").to_eng_string(), "100E-12");
+    assert_eq!(Decimal::from("123.45").to_eng_string(), "123.45");
+    assert_eq!(
+        Context::local(Context { prec: 5, ..Context::default() }, || Decimal::from("0.~3").to_eng_string()),
+        "0.33333"
+    );
+}
+
+#[rstest]
+fn format(setup: Fixture) {
+    let d = Decimal::from("1234.5678");
+    assert_eq!(format!("{}", d), "1234.5678");
+    assert_eq!(format!("{:.2}", d), "1234.57");
+    assert_eq!(format!("{:.0}", Decimal::from("2.5")), "2");
+    assert_eq!(format!("{:.3}", setup.zero), "0.000");
+    assert_eq!(format!("{:.3}", Decimal::from("0.~6")), "0.667");
+    assert_eq!(format!("{:#.3}", d), "1.23e+3");
+    assert_eq!(format!("{:#.2}", Decimal::from("-1.999")), "-2.0");
+    assert_eq!(format!("{:#}", Decimal::from("0.00000012")), "1.2e-7");
+    assert_eq!(format!("{:.3e}", d), "1.235e+3");
+    assert_eq!(format!("{:e}", Decimal::from(100)), "1e+2");
+    assert_eq!(format!("{:.1E}", Decimal::from("9.99")), "1.0E+1");
+    assert_eq!(format!("{:.3e}", setup.zero), "0.000e+3");
+
+    // the digits beyond i128 are exact
+    assert_eq!(format!("{:.30}", Decimal::from(12345678901i64)), "12345678901.000000000000000000000000000000");
+    assert_eq!(format!("{:.50}", Decimal::from("0.~3")), format!("0.{}", "3".repeat(50)));
+    assert_eq!(format!("{:.40}", Decimal::from("0.~6")), "0.6666666666666666666666666666666666666667");
+    assert_eq!(format!("{:.45e}", Decimal::from("0.~142857")), "1.428571428571428571428571428571428571428571429e-1");
+
+    assert_eq!(format!("{:+}", setup.positive), "+0.3");
+    assert_eq!(format!("{:8.2}", setup.negative), "   -0.30");
+    assert_eq!(format!("{:<8.2}|", setup.negative), "-0.30   |");
+    assert_eq!(format!("{:^9}", setup.positive), "   0.3   ");
+    assert_eq!(format!("{:+08.2}", setup.positive), "+0000.30");
+    assert_eq!(format!("{:08}", Decimal::NAN), "     NaN");
+    assert_eq!(format!("{:.2}", -Decimal::from(0)), "-0.00");
+    assert_eq!(format!("{:+.2}", Decimal::INFINITY), "+Infinity");
+}
+
+#[rstest]
+fn to_string_radix() {
+    assert_eq!(Decimal::from("0.1").to_string_radix(2), "0.0~0011#2");
+    assert_eq!(Decimal::from("0.1").to_string_radix(36), "0.3~L#36");
+    assert_eq!(Decimal::from("-0.~3").to_string_radix(2), "-0.~01#2");
+    assert_eq!(Decimal::from("0.~142857").to_string_radix(8), "0.~1#8");
+    assert_eq!((Decimal::from(22) / Decimal::from(7)).to_string_radix(7), "3.1#7");
+    assert_eq!(Decimal::from("33.~3").to_string_radix(3), "1020.1#3");
+    assert_eq!((Decimal::from(1234) / Decimal::from(99)).to_string_radix(16), "C.~76F31219DBCC486#16");
+    assert_eq!(Decimal::from(0).to_string_radix(5), "0#5");
+    assert_eq!(Decimal::from("1.5").to_string_radix(10), "1.5#10");
+
+    // the repeating part of 1/131 in binary has 130 digits, more than an i128 holds
+    let d = Decimal::from(1) / Decimal::from(131);
+    assert_eq!(Decimal::from(d.to_string_radix(2).as_str()), d);
+
+    // round-trip across bases
+    for s in ["0.1", "-12.~345", "0.0~0011#2", "ZZ.~Z#36", "3.1#7"] {
+        let d = Decimal::from(s);
+        for radix in 2..=36 {
+            assert_eq!(Decimal::from(d.to_string_radix(radix).as_str()), d);
+        }
+    }
+}
+
+#[rstest]
+#[should_panic(expected = "Error: Require 2 <= radix <= 36 for to_string_radix().")]
+fn bad_to_string_radix() {
+    let _ = Decimal::from("1.5").to_string_radix(37);
 }
 
 #[rstest]
@@ -173,4 +448,214 @@ fn parse(setup: Fixture) {
     assert!("0.|3".parse::<Decimal>().is_err());
     assert!("0.3|".parse::<Decimal>().is_err());
     assert!("0|3".parse::<Decimal>().is_err());
+
+    assert_eq!("1.5e-3".parse(), Ok(Decimal::from("0.0015")));
+    assert_eq!("2E10".parse(), Ok(Decimal::from(20_000_000_000i64)));
+    assert_eq!("-2.5E+2".parse(), Ok(Decimal::from(-250)));
+    assert_eq!("0.~3e1".parse(), Ok(Decimal::from("3.~3")));
+    assert_eq!("1_000.000_1".parse(), Ok(Decimal::from("1000.0001")));
+    assert_eq!(".5".parse(), Ok(Decimal::from("0.5")));
+    assert_eq!("5.".parse(), Ok(Decimal::from(5)));
+    assert_eq!("1.5000000000000000000000000000000000000000000000".parse(), Ok(Decimal::from("1.5")));
+    assert_eq!("0e999".parse(), Ok(Decimal::new()));
+    assert_eq!("1e-20".parse::<Decimal>().map(|d| d.as_fraction()), Ok(Fraction::from((1, 10i128.pow(20)))));
+    assert_eq!("FF.8#16".parse(), Ok(Decimal::from("255.5")));
+    assert_eq!("1e#16".parse(), Ok(Decimal::from(30)));
+
+    assert_eq!("".parse::<Decimal>(), Err(ParseDecimalError::Empty));
+    assert_eq!(".".parse::<Decimal>(), Err(ParseDecimalError::InvalidDigit));
+    assert_eq!("1__0".parse::<Decimal>(), Err(ParseDecimalError::InvalidDigit));
+    assert_eq!("_1".parse::<Decimal>(), Err(ParseDecimalError::InvalidDigit));
+    assert_eq!("0.~".parse::<Decimal>(), Err(ParseDecimalError::InvalidDigit));
+    assert_eq!("0.2#2".parse::<Decimal>(), Err(ParseDecimalError::InvalidDigit));
+    assert_eq!("1#37".parse::<Decimal>(), Err(ParseDecimalError::InvalidRadix));
+    assert_eq!("1#x".parse::<Decimal>(), Err(ParseDecimalError::InvalidRadix));
+    assert_eq!("1e".parse::<Decimal>(), Err(ParseDecimalError::InvalidExponent));
+    assert_eq!("1e+-2".parse::<Decimal>(), Err(ParseDecimalError::InvalidExponent));
+    assert_eq!("1".repeat(40).parse::<Decimal>(), Err(ParseDecimalError::Overflow));
+    // only the reduced value has to fit, so a long repeating part of a small value is fine
+    assert_eq!(("0.~".to_string() + &"1".repeat(40)).parse(), Ok(Decimal::from("0.~1")));
+    assert_eq!(("0.".to_string() + &"1".repeat(40)).parse::<Decimal>(), Err(ParseDecimalError::Overflow));
+    assert_eq!("1e50".parse::<Decimal>(), Err(ParseDecimalError::Overflow));
+    assert_eq!("Infinity".parse(), Ok(Decimal::INFINITY));
+    assert_eq!("-inf".parse(), Ok(Decimal::NEG_INFINITY));
+    assert!("NaN".parse::<Decimal>().unwrap().is_qnan());
+    assert_eq!("sNaN12".parse::<Decimal>().unwrap().to_string(), "sNaN12");
+    assert_eq!("-0".parse::<Decimal>().unwrap().to_string(), "-0");
+    assert_eq!("nan1x".parse::<Decimal>(), Err(ParseDecimalError::InvalidDigit));
+}
+
+#[rstest]
+fn special_values(setup: Fixture) {
+    // invalid operations return NaN instead of panicking
+    Context::set(Context {
+        traps: Signals::new(),
+        ..Context::default()
+    });
+    let inf = Decimal::INFINITY;
+    let nan = Decimal::NAN;
+    let neg_zero = -setup.zero;
+
+    // predicates
+    assert!(nan.is_nan() && nan.is_qnan() && !nan.is_snan());
+    assert!(Decimal::from("sNaN").is_snan());
+    assert!(inf.is_infinite() && !inf.is_finite());
+    assert!(Decimal::NEG_INFINITY.is_signed());
+    assert!(neg_zero.is_signed() && neg_zero.is_zero());
+    assert!(!setup.zero.is_signed());
+
+    // format
+    assert_eq!(inf.to_string(), "Infinity");
+    assert_eq!((-inf).to_string(), "-Infinity");
+    assert_eq!(nan.to_string(), "NaN");
+    assert_eq!((-nan).to_string(), "-NaN");
+    assert_eq!(neg_zero.to_string(), "-0");
+    assert_eq!(inf.to_string_radix(2), "Infinity");
+
+    // arithmetic
+    assert_eq!(inf + Decimal::from(1), inf);
+    assert!((inf - inf).is_nan());
+    assert!((inf * setup.zero).is_nan());
+    assert_eq!(inf * Decimal::from(-2), Decimal::NEG_INFINITY);
+    assert!((inf / inf).is_nan());
+    assert_eq!(Decimal::from(1) / inf, setup.zero);
+    assert!((Decimal::from(-1) / inf).is_signed());
+    assert!((inf % Decimal::from(2)).is_nan());
+    assert_eq!(Decimal::from(3) % inf, Decimal::from(3));
+    assert!((neg_zero + neg_zero).is_signed());
+    assert!(!(neg_zero + setup.zero).is_signed());
+    assert!((Decimal::from(1) + nan).is_nan());
+    assert_eq!((Decimal::from("sNaN7") + nan).to_string(), "NaN7");
+
+    // compare
+    assert!(neg_zero == setup.zero);
+    assert!(Decimal::NEG_INFINITY < Decimal::MIN && Decimal::MAX < inf);
+    assert_eq!(HashSet::from([neg_zero, setup.zero]).len(), 1);
+
+    // NaN equals itself and is ordered beyond the infinities
+    assert!(nan == nan);
+    assert!(nan != -nan && nan != Decimal::from("sNaN") && nan != Decimal::from("NaN1"));
+    assert!(nan > inf && -nan < Decimal::NEG_INFINITY);
+    assert_eq!(nan.partial_cmp(&setup.zero), Some(Ordering::Greater));
+    assert_eq!(HashSet::from([nan, nan, -nan]).len(), 2);
+
+    let mut values = ["1", "-NaN", "NaN", "-0", "sNaN", "-Infinity", "0", "-sNaN", "Infinity", "-2"].map(Decimal::from);
+    values.sort_by(Decimal::compare_total);
+    assert_eq!(
+        values.map(|x| x.to_string()),
+        ["-NaN", "-sNaN", "-Infinity", "-2", "-0", "0", "1", "Infinity", "sNaN", "NaN"]
+    );
+    // the total order agrees with `Ord`, except that -0 equals 0
+    assert!(values.windows(2).all(|w| w[0] <= w[1]));
+    assert_eq!(values.iter().max(), Some(&nan));
+
+    // functions
+    assert_eq!(inf.sqrt(5), inf);
+    assert!(neg_zero.sqrt(5).is_signed());
+    assert_eq!((-inf).exp(5), setup.zero);
+    assert_eq!(setup.zero.ln(5), Decimal::NEG_INFINITY);
+    assert_eq!(inf.log10(5), inf);
+    assert!(inf.sin(5).is_nan());
+    assert_eq!(inf.atan(5), Decimal::from("1.5708"));
+    assert_eq!(Decimal::pow(Decimal::from(2), -inf, 5), setup.zero);
+    assert!(Decimal::pow(setup.zero, setup.zero, 5).is_nan());
+    assert!(Decimal::pow(-inf, Decimal::from("0.5"), 5).is_nan());
+    assert!(Decimal::pow(-inf, inf, 5).is_nan());
+    assert_eq!(Decimal::pow(-inf, Decimal::from(3), 5), -inf);
+    assert_eq!(Decimal::pow(-inf, setup.zero, 5), Decimal::from(1));
+    assert!(nan.exp(5).is_nan());
+
+    // transform
+    assert_eq!(f64::from(inf), f64::INFINITY);
+    assert!(f64::from(nan).is_nan());
+    assert!(f64::from(neg_zero).is_sign_negative());
+    assert_eq!(Decimal::from(f64::NEG_INFINITY), Decimal::NEG_INFINITY);
+    assert!(Decimal::from(-0.0).is_signed());
+    assert_eq!(Int::try_from(inf), Err(ConversionError::NotFinite));
+}
+
+#[rstest]
+fn signals(setup: Fixture) {
+    assert_eq!(Context::current().flags, Signals::new());
+    assert_eq!(setup.positive.round_to(0), setup.zero);
+    assert!(Context::current().flags.contains(Signal::Inexact));
+    assert!(Context::current().flags.contains(Signal::Rounded));
+
+    Context::clear_flags();
+    assert_eq!(Decimal::from("1.25").round_to(2), Decimal::from("1.25"));
+    assert_eq!(Decimal::from(4).sqrt(5), Decimal::from(2));
+    assert!(Context::current().flags.is_empty());
+    let _ = Decimal::from(2).sqrt(5);
+    assert!(Context::current().flags.contains(Signal::Inexact));
+
+    let untrapped = Context {
+        traps: Signals::new(),
+        ..Context::default()
+    };
+    Context::local(untrapped, || {
+        assert_eq!(setup.positive / setup.zero, Decimal::INFINITY);
+        assert_eq!(setup.negative / setup.zero, Decimal::NEG_INFINITY);
+        assert!((setup.zero / setup.zero).is_nan());
+        assert!((setup.positive % setup.zero).is_nan());
+        assert!(Decimal::from(-1).sqrt(5).is_nan());
+        assert_eq!(Decimal::from(1000).exp(5), Decimal::INFINITY);
+        assert_eq!(Decimal::from(-1000).exp(5), setup.zero);
+        assert_eq!(Decimal::from(-2).scaleb(40), Decimal::NEG_INFINITY);
+        assert_eq!(Decimal::from("0.~3").scaleb(-40), setup.zero);
+        assert!(Decimal::from("0.~3").quantize(40, Rounding::HalfEven).is_nan());
+
+        // the arithmetic overflows to the infinities instead of wrapping
+        let max = Decimal::from(i128::MAX);
+        assert_eq!(max + Decimal::from(1), Decimal::INFINITY);
+        assert_eq!(-max - Decimal::from(2), Decimal::NEG_INFINITY);
+        assert_eq!(Decimal::from(10i128.pow(23)) * Decimal::from(-(10i128.pow(23))), Decimal::NEG_INFINITY);
+        assert_eq!(max / Decimal::from("0.5"), Decimal::INFINITY);
+        assert_eq!(max / Decimal::from(7) * Decimal::from(7), max);
+        assert_eq!(Decimal::from("0.~3") + Decimal::from("0.~6"), Decimal::from(1));
+        assert_eq!(max % Decimal::from("0.3"), Decimal::INFINITY);
+        assert_eq!(-max % Decimal::from("0.3"), Decimal::NEG_INFINITY);
+        assert_eq!(max % Decimal::from(10), Decimal::from(7));
+        assert_eq!(Decimal::MAX.round_to(-1), Decimal::INFINITY);
+        assert_eq!((-Decimal::MAX).round_to(-1), Decimal::NEG_INFINITY);
+
+        let flags = Context::current().flags;
+        for signal in [Signal::DivisionByZero, Signal::InvalidOperation, Signal::Overflow, Signal::Inexact] {
+            assert!(flags.contains(signal), "{signal:?}");
+        }
+    });
+    // the flags of a local context are discarded
+    assert!(!Context::current().flags.contains(Signal::DivisionByZero));
+
+    let mut traps = Signals::from([Signal::DivisionByZero]);
+    traps.insert(Signal::Inexact);
+    traps.remove(Signal::DivisionByZero);
+    assert_eq!(traps, Signals::from([Signal::Inexact]));
+}
+
+#[rstest]
+#[should_panic(expected = "Error: Divide by zero.")]
+#[case::panic_with_message(1)]
+#[should_panic(expected = "Error: Invalid operation.")]
+#[case::panic_with_message(2)]
+#[should_panic(expected = "Error: Inexact result.")]
+#[case::panic_with_message(3)]
+#[should_panic(expected = "Error: The result does not fit in a decimal.")]
+#[case::panic_with_message(4)]
+#[should_panic(expected = "Error: The result does not fit in a decimal.")]
+#[case::panic_with_message(5)]
+fn bad_signals(setup: Fixture, #[case] case: i32) {
+    match case {
+        1 => _ = setup.positive / setup.zero,
+        2 => _ = Decimal::INFINITY + Decimal::NEG_INFINITY,
+        3 => {
+            Context::set(Context {
+                traps: Signals::from([Signal::Inexact]),
+                ..Context::default()
+            });
+            _ = Decimal::from(2).sqrt(5);
+        }
+        4 => _ = Decimal::from(i128::MAX) + Decimal::from(1),
+        5 => _ = Decimal::from(i128::MAX) % Decimal::from("0.3"),
+        _ => unreachable!(),
+    }
 }
@@ -0,0 +1,127 @@
+use std::f64::consts::PI;
+
+use pyinrs::{fft, Complex, Int, List};
+use rstest::{fixture, rstest};
+
+struct Fixture {
+    signals: Vec<List<Complex>>,
+}
+
+#[fixture]
+fn setup() -> Fixture {
+    // deterministic pseudo-random signals of power-of-two, prime and composite lengths
+    let signal = |n: usize| (0..n).map(|k| Complex::from(((k as f64 * 0.7).sin() * 3., (k as f64 * 1.3).cos() - 0.5))).collect();
+    Fixture {
+        signals: [1, 2, 3, 4, 5, 7, 8, 12, 16, 17, 31, 64, 100].map(signal).to_vec(),
+    }
+}
+
+fn dft(x: &List<Complex>, sign: f64) -> List<Complex> {
+    let n = x.len() as usize;
+    (0..n)
+        .map(|k| {
+            x.iter().enumerate().fold(Complex::new(), |sum, (j, &z)| {
+                sum + z * Complex::from_polar(1., sign * 2. * PI * (j * k % n) as f64 / n as f64)
+            })
+        })
+        .collect()
+}
+
+fn assert_close(left: &List<Complex>, right: &List<Complex>) {
+    assert_eq!(left.len(), right.len());
+    for (x, y) in left.iter().zip(right.iter()) {
+        assert!(x.is_close(y, 1e-9, 1e-9), "{x} != {y}");
+    }
+}
+
+#[rstest]
+fn exact() {
+    let x = List::from([1., 2., 3., 4.].map(Complex::from));
+    let y = List::from([(10., 0.), (-2., 2.), (-2., 0.), (-2., -2.)].map(Complex::from));
+    assert_eq!(fft::fft(&x), y);
+    assert_eq!(fft::ifft(&y), x);
+
+    assert_eq!(fft::fft(&List::from([Complex::from((1., 2.))])), List::from([Complex::from((1., 2.))]));
+}
+
+#[rstest]
+fn transform(setup: Fixture) {
+    for x in &setup.signals {
+        assert_close(&fft::fft(x), &dft(x, -1.));
+        assert_close(&fft::ifft(x), &dft(x, 1.).into_iter().map(|z| z / x.len()).collect());
+        assert_close(&fft::ifft(&fft::fft(x)), x);
+    }
+}
+
+#[rstest]
+fn real(setup: Fixture) {
+    for x in &setup.signals {
+        let real: List<f64> = x.iter().map(|z| z.real()).collect();
+        let spectrum = fft::rfft(&real);
+        let full: List<Complex> = real.iter().map(|&r| Complex::from(r)).collect();
+        let expected: List<Complex> = dft(&full, -1.).into_iter().take(real.len() as usize / 2 + 1).collect();
+        assert_close(&spectrum, &expected);
+
+        let back = fft::irfft(&spectrum, real.len() as usize);
+        assert_close(&back.into_iter().map(Complex::from).collect(), &full);
+    }
+
+    // the imaginary parts of the zero and Nyquist frequencies are ignored, the missing terms are zeros
+    let spectrum = List::from([(4., 9.), (0., -2.), (2., 9.)].map(Complex::from));
+    assert_eq!(fft::irfft(&spectrum, 4), List::from([1.5, 1.5, 1.5, -0.5]));
+    assert_eq!(fft::irfft(&spectrum, 2), List::from([2., 2.]));
+    assert_eq!(fft::irfft(&List::from([Complex::from(8.)]), 4), List::from([2., 2., 2., 2.]));
+}
+
+#[rstest]
+fn convolve() {
+    assert_eq!(
+        fft::convolve(&List::from([1., 2., 3.]), &List::from([0., 1., 0.5])),
+        List::from([0., 1., 2.5, 4., 1.5])
+    );
+    assert_eq!(fft::convolve(&List::from([2.]), &List::from([3.])), List::from([6.]));
+    assert_eq!(fft::convolve(&List::new(), &List::from([3.])), List::new());
+
+    let a = List::from([(1., 1.), (0., 2.)].map(Complex::from));
+    let b = List::from([(2., 0.), (1., -1.), (0., 1.)].map(Complex::from));
+    let expected = List::from([(2., 2.), (2., 4.), (1., 3.), (-2., 0.)].map(Complex::from));
+    assert_close(&fft::convolve_complex(&a, &b), &expected);
+    assert_eq!(fft::convolve_complex(&a, &List::new()), List::new());
+
+    // schoolbook multiplication of integers is a convolution of the digits
+    let digits = |s: &str| s.bytes().rev().map(|c| (c - b'0') as f64).collect::<List<_>>();
+    let (x, y) = ("31415926535897932384626433832795", "27182818284590452353602874713527");
+    let mut carry = 0u64;
+    let mut product = String::new();
+    for d in fft::convolve(&digits(x), &digits(y)) {
+        carry += d.round() as u64;
+        product.insert(0, char::from(b'0' + (carry % 10) as u8));
+        carry /= 10;
+    }
+    if carry > 0 {
+        product.insert_str(0, &carry.to_string());
+    }
+    assert_eq!(Int::from(product.as_str()), Int::from(x) * Int::from(y));
+}
+
+#[rstest]
+#[should_panic(expected = "Error: The container is empty.")]
+#[case::panic_with_message(1)]
+#[should_panic(expected = "Error: The container is empty.")]
+#[case::panic_with_message(2)]
+#[should_panic(expected = "Error: The container is empty.")]
+#[case::panic_with_message(3)]
+fn bad_fft(#[case] case: i32) {
+    match case {
+        1 => {
+            let _ = fft::fft(&List::new());
+        }
+        2 => {
+            let _ = fft::rfft(&List::new());
+        }
+        3 => {
+            let _ = fft::irfft(&List::from([Complex::from(1.)]), 0);
+        }
+        _ => unreachable!(),
+    }
+}
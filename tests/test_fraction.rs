@@ -1,4 +1,4 @@
-use pyinrs::Fraction;
+use pyinrs::{ConversionError, Decimal, Fraction, Int, List, ParseFractionError};
 use rstest::{fixture, rstest};
 
 struct Fixture {
@@ -231,6 +231,57 @@ fn from_float() {
     assert_eq!(Fraction::from(std::f32::consts::E), Fraction::from((2718281745910645i128, 1000000000000000)));
 }
 
+#[rstest]
+fn from_float_exact() {
+    assert_eq!(Fraction::from_float(0.0), Ok(Fraction::from(0)));
+    assert_eq!(Fraction::from_float(3.0), Ok(Fraction::from(3)));
+    assert_eq!(Fraction::from_float(-0.75), Ok(Fraction::from((-3, 4))));
+    assert_eq!(Fraction::from_float(0.1), Ok(Fraction::from((3602879701896397i128, 36028797018963968))));
+    assert_eq!(Fraction::from_float(1e-5), Ok(Fraction::from((5902958103587057i128, 590295810358705651712))));
+    assert_eq!(Fraction::from_float(1e30), Ok(Fraction::from(1000000000000000019884624838656i128)));
+    assert_eq!(Fraction::from_float(2.0f64.powi(-126)), Ok(Fraction::from((1, 1i128 << 126))));
+
+    assert_eq!(Fraction::from_float(2.0f64.powi(-127)), Err(ConversionError::Overflow));
+    assert_eq!(Fraction::from_float(1e40), Err(ConversionError::Overflow));
+    assert_eq!(Fraction::from_float(f64::MIN_POSITIVE / 2.0), Err(ConversionError::Overflow));
+    assert_eq!(Fraction::from_float(f64::INFINITY), Err(ConversionError::NotFinite));
+    assert_eq!(Fraction::from_float(f64::NAN), Err(ConversionError::NotFinite));
+
+    assert_eq!(Fraction::from_float_rounded(0.1), Fraction::from((1, 10)));
+    assert_eq!(Fraction::from_float_rounded(1.1), Fraction::from(1.1));
+}
+
+#[rstest]
+fn numeric_tower(setup: Fixture) {
+    // Int <-> Fraction
+    assert_eq!(Fraction::try_from(Int::from(-42)), Ok(Fraction::from(-42)));
+    assert_eq!(Fraction::try_from(&Int::from(i128::MIN.to_string().as_str())), Ok(Fraction::from(i128::MIN)));
+    assert_eq!(Fraction::try_from(Int::from(i128::MAX) + Int::from(1)), Err(ConversionError::Overflow));
+    assert_eq!(Int::try_from(Fraction::from((6, 3))), Ok(Int::from(2)));
+    assert_eq!(Int::try_from(setup.positive), Err(ConversionError::NotInteger));
+
+    // Int op Fraction -> Fraction
+    assert_eq!(Int::from(1) + setup.positive, Fraction::from((3, 2)));
+    assert_eq!(setup.positive - Int::from(1), Fraction::from((-1, 2)));
+    assert_eq!(&Int::from(3) * setup.negative, Fraction::from((-3, 2)));
+    assert_eq!(setup.positive / &Int::from(3), Fraction::from((1, 6)));
+
+    let mut f = setup.positive;
+    f += Int::from(2);
+    f *= &Int::from(2);
+    assert_eq!(f, Fraction::from(5));
+
+    // Fraction op Decimal -> Decimal
+    assert_eq!(setup.positive + Decimal::from("0.25"), Decimal::from("0.75"));
+    assert_eq!(Decimal::from("0.~3") * Fraction::from(3), Decimal::from(1));
+}
+
+#[rstest]
+#[should_panic(expected = "Error: The integer is too large to convert to a fraction.")]
+fn bad_numeric_tower() {
+    let _ = Int::from("1".repeat(40).as_str()) + Fraction::from(1);
+}
+
 #[rstest]
 fn to_float() {
     let zero = Fraction::from((0, 2));
@@ -249,6 +300,101 @@ fn to_float() {
     assert_eq!(f32::from(neg_half), -0.5);
 }
 
+#[rstest]
+fn pow() {
+    assert_eq!(Fraction::pow(Fraction::from((2, 3)), 0), Fraction::from(1));
+    assert_eq!(Fraction::pow(Fraction::from((2, 3)), 3), Fraction::from((8, 27)));
+    assert_eq!(Fraction::pow(Fraction::from((-2, 3)), 3), Fraction::from((-8, 27)));
+    assert_eq!(Fraction::pow(Fraction::from((-2, 3)), -3), Fraction::from((-27, 8)));
+    assert_eq!(Fraction::pow(Fraction::from((1, 2)), -2), Fraction::from(4));
+    assert_eq!(Fraction::pow(Fraction::from(0), 2), Fraction::from(0));
+
+    // checked_pow()
+    assert_eq!(Fraction::checked_pow(Fraction::from((-2, 3)), -3), Some(Fraction::from((-27, 8))));
+    assert_eq!(Fraction::checked_pow(Fraction::from(2), 126), Some(Fraction::from(1i128 << 126)));
+    assert_eq!(Fraction::checked_pow(Fraction::from(2), 127), None);
+    assert_eq!(Fraction::checked_pow(Fraction::from((1, 10)), 39), None);
+    assert_eq!(Fraction::checked_pow(Fraction::from(0), -1), None);
+
+    // pow_rational()
+    assert_eq!(Fraction::pow_rational(Fraction::from((4, 9)), Fraction::from((1, 2))), Some(Fraction::from((2, 3))));
+    assert_eq!(Fraction::pow_rational(Fraction::from((4, 9)), Fraction::from((-3, 2))), Some(Fraction::from((27, 8))));
+    assert_eq!(Fraction::pow_rational(Fraction::from(-8), Fraction::from((1, 3))), Some(Fraction::from(-2)));
+    assert_eq!(Fraction::pow_rational(Fraction::from(-4), Fraction::from((1, 2))), None);
+    assert_eq!(Fraction::pow_rational(Fraction::from(2), Fraction::from((1, 2))), None);
+    assert_eq!(Fraction::pow_rational(Fraction::from(1), Fraction::from((1, 1000))), Some(Fraction::from(1)));
+    assert_eq!(Fraction::pow_rational(Fraction::from(i128::MAX), Fraction::from((1, 200))), None);
+    assert_eq!(
+        Fraction::pow_rational(Fraction::from(1i128 << 126), Fraction::from((1, 126))),
+        Some(Fraction::from(2))
+    );
+    assert_eq!(Fraction::pow_rational(Fraction::from(3), Fraction::from(2)), Some(Fraction::from(9)));
+    assert_eq!(Fraction::pow_rational(Fraction::from(2), Fraction::from(200)), None);
+    assert_eq!(Fraction::pow_rational(Fraction::from((1, 10)), Fraction::from((-80, 2))), None);
+    assert_eq!(Fraction::pow_rational(Fraction::from(-1), Fraction::from(i128::MAX)), Some(Fraction::from(-1)));
+    assert_eq!(Fraction::pow_rational(Fraction::from(1), Fraction::from(i128::MIN + 1)), Some(Fraction::from(1)));
+}
+
+#[rstest]
+#[case::no_panic(1)]
+#[should_panic(expected = "Error: Divide by zero.")]
+#[case::panic_with_message(2)]
+#[should_panic(expected = "Error: The result does not fit in a fraction.")]
+#[case::panic_with_message(3)]
+fn bad_pow(#[case] case: i32) {
+    match case {
+        1 => assert_eq!(Fraction::pow(Fraction::from(-2), 125), Fraction::from(-(1i128 << 125))),
+        2 => {
+            let _ = Fraction::pow(Fraction::from(0), -1);
+        }
+        3 => {
+            let _ = Fraction::pow(Fraction::from(2), 200);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[rstest]
+fn rounding() {
+    let cases = [
+        ((7, 2), 3, 4, 3, 4),
+        ((5, 2), 2, 3, 2, 2),
+        ((-7, 2), -4, -3, -3, -4),
+        ((-5, 2), -3, -2, -2, -2),
+        ((1, 3), 0, 1, 0, 0),
+        ((-1, 3), -1, 0, 0, 0),
+        ((2, 1), 2, 2, 2, 2),
+    ];
+    for (ratio, floor, ceil, trunc, round) in cases {
+        let f = Fraction::from(ratio);
+        assert_eq!(f.floor(), Int::from(floor));
+        assert_eq!(f.ceil(), Int::from(ceil));
+        assert_eq!(f.trunc(), Int::from(trunc));
+        assert_eq!(f.round(), Int::from(round));
+    }
+
+    assert_eq!(Fraction::from((1, 3)).round_to(1), Fraction::from((3, 10)));
+    assert_eq!(Fraction::from((-1, 3)).round_to(2), Fraction::from((-33, 100)));
+    assert_eq!(Fraction::from((1, 8)).round_to(2), Fraction::from((3, 25))); // 0.125 -> 0.12
+    assert_eq!(Fraction::from((3, 8)).round_to(2), Fraction::from((19, 50))); // 0.375 -> 0.38
+    assert_eq!(Fraction::from((1234567, 1000)).round_to(-2), Fraction::from(1200));
+    assert_eq!(Fraction::from(1250).round_to(-2), Fraction::from(1200));
+    assert_eq!(Fraction::from(1350).round_to(-2), Fraction::from(1400));
+    assert_eq!(Fraction::from((7, 2)).round_to(0), Fraction::from(4));
+
+    // the digits beyond i128 do not change a terminating decimal or a small value
+    assert_eq!(Fraction::from((1, 4)).round_to(40), Fraction::from((1, 4)));
+    assert_eq!(Fraction::from(i128::MAX).round_to(100), Fraction::from(i128::MAX));
+    assert_eq!(Fraction::from(i128::MAX).round_to(-39), Fraction::from(0));
+    assert_eq!(Fraction::from((7, 3)).round_to(-38), Fraction::from(0));
+}
+
+#[rstest]
+#[should_panic(expected = "Error: The result does not fit in a fraction.")]
+fn bad_rounding() {
+    let _ = Fraction::from((1, 3)).round_to(40);
+}
+
 #[rstest]
 fn gcd_lcm() {
     // gcd()
@@ -274,11 +420,83 @@ fn gcd_lcm() {
     assert_eq!(Fraction::lcm(Fraction::from((-1, 2)), Fraction::from((-3, 4))), Fraction::from((3, 2)));
 }
 
+#[rstest]
+fn stern_brocot() {
+    // mediant()
+    assert_eq!(Fraction::mediant(Fraction::from((1, 2)), Fraction::from((2, 3))), Fraction::from((3, 5)));
+    assert_eq!(Fraction::mediant(Fraction::from((1, 3)), Fraction::from((1, 3))), Fraction::from((1, 3)));
+
+    // farey()
+    assert_eq!(Fraction::farey(1), List::from([Fraction::from(0), Fraction::from(1)]));
+    assert_eq!(
+        Fraction::farey(5),
+        ["0", "1/5", "1/4", "1/3", "2/5", "1/2", "3/5", "2/3", "3/4", "4/5", "1"]
+            .into_iter()
+            .map(Fraction::from)
+            .collect()
+    );
+    assert_eq!(Fraction::farey(100).len(), 3045);
+
+    // stern_brocot_path() and from_stern_brocot_path()
+    assert_eq!(Fraction::from(1).stern_brocot_path(), "");
+    assert_eq!(Fraction::from((3, 2)).stern_brocot_path(), "RL");
+    assert_eq!(Fraction::from((3, 7)).stern_brocot_path(), "LLRR");
+    assert_eq!(Fraction::from(4).stern_brocot_path(), "RRR");
+    assert_eq!(Fraction::from_stern_brocot_path(""), Fraction::from(1));
+    assert_eq!(Fraction::from_stern_brocot_path("RL"), Fraction::from((3, 2)));
+    assert_eq!(Fraction::from_stern_brocot_path("LLRR"), Fraction::from((3, 7)));
+    for f in Fraction::farey(20).into_iter().skip(1) {
+        assert_eq!(Fraction::from_stern_brocot_path(&f.stern_brocot_path()), f);
+    }
+
+    // simplest_between()
+    assert_eq!(Fraction::simplest_between(Fraction::from((1, 3)), Fraction::from((1, 2))), Fraction::from((1, 2)));
+    assert_eq!(Fraction::simplest_between(Fraction::from((3, 10)), Fraction::from((4, 10))), Fraction::from((1, 3)));
+    assert_eq!(Fraction::simplest_between("3.14".into(), "3.1416".into()), Fraction::from((157, 50)));
+    assert_eq!(Fraction::simplest_between("3.1415".into(), "3.1416".into()), Fraction::from((333, 106)));
+    assert_eq!(Fraction::simplest_between("3.14159".into(), "3.1416".into()), Fraction::from((355, 113)));
+    assert_eq!(Fraction::simplest_between(Fraction::from((-7, 2)), Fraction::from((-10, 3))), Fraction::from((-7, 2)));
+    assert_eq!(Fraction::simplest_between(Fraction::from((5, 2)), Fraction::from((7, 2))), Fraction::from(3));
+    assert_eq!(Fraction::simplest_between(Fraction::from((1, 2)), Fraction::from((-1, 2))), Fraction::from(0));
+}
+
+#[rstest]
+#[should_panic(expected = "Error: Require n >= 1 for farey(n).")]
+fn bad_farey() {
+    let _ = Fraction::farey(0);
+}
+
+#[rstest]
+#[should_panic(expected = "Error: Wrong Stern-Brocot path.")]
+fn bad_stern_brocot_path() {
+    let _ = Fraction::from_stern_brocot_path("LRX");
+}
+
 #[rstest]
 fn format(setup: Fixture) {
     assert_eq!(format!("{}", setup.zero), "0");
     assert_eq!(format!("{}", setup.positive), "1/2");
     assert_eq!(format!("{}", setup.negative), "-1/2");
+
+    assert_eq!(format!("{:#}", Fraction::from((3, 2))), "1 1/2");
+    assert_eq!(format!("{:#}", Fraction::from((-7, 3))), "-2 1/3");
+    assert_eq!(format!("{:#}", setup.negative), "-1/2");
+    assert_eq!(format!("{:#}", Fraction::from(4)), "4");
+
+    assert_eq!(Fraction::from((3, 2)).to_mixed_string(), "1 1/2");
+    assert_eq!(Fraction::from((-3, 2)).to_mixed_string(), "-1 1/2");
+    assert_eq!(setup.zero.to_mixed_string(), "0");
+
+    assert_eq!(setup.positive.to_unicode_string(), "½");
+    assert_eq!(setup.negative.to_unicode_string(), "-½");
+    assert_eq!(Fraction::from((7, 8)).to_unicode_string(), "⅞");
+    assert_eq!(Fraction::from((11, 12)).to_unicode_string(), "¹¹/₁₂");
+    assert_eq!(Fraction::from((-3, 2)).to_unicode_string(), "-³/₂");
+    assert_eq!(Fraction::from(-5).to_unicode_string(), "-5");
+
+    assert_eq!(setup.positive.to_latex_string(), r"\frac{1}{2}");
+    assert_eq!(Fraction::from((-22, 7)).to_latex_string(), r"-\frac{22}{7}");
+    assert_eq!(setup.zero.to_latex_string(), "0");
 }
 
 #[rstest]
@@ -296,4 +514,45 @@ fn parse(setup: Fixture) {
     assert!("1/z2".parse::<Fraction>().is_err());
     assert!("1/2z".parse::<Fraction>().is_err());
     assert!("1|2".parse::<Fraction>().is_err());
+
+    // spaces around `/`
+    assert_eq!(Fraction::from((3, 4)), " 3 / 4 ".parse().unwrap());
+    assert_eq!(Fraction::from((-3, 4)), "3 /-4".parse().unwrap());
+
+    // mixed numbers
+    assert_eq!(Fraction::from((3, 2)), "1 1/2".parse().unwrap());
+    assert_eq!(Fraction::from((-3, 2)), "-1 1/2".parse().unwrap());
+    assert_eq!(Fraction::from((-1, 2)), "-0 1/2".parse().unwrap());
+    assert_eq!(Fraction::from((11, 4)), "2  6 / 8".parse().unwrap());
+
+    // decimals and scientific notation
+    assert_eq!(Fraction::from((5, 4)), "1.25".parse().unwrap());
+    assert_eq!(Fraction::from((-1, 2)), "-.5".parse().unwrap());
+    assert_eq!(Fraction::from(5), "+5.".parse().unwrap());
+    assert_eq!(Fraction::from((3, 2000)), "1.5e-3".parse().unwrap());
+    assert_eq!(Fraction::from(20000000000i64), "2E10".parse().unwrap());
+    assert_eq!(Fraction::from(1), "1.00000000000000000000000000000000000000000000".parse().unwrap());
+    assert_eq!(Fraction::from(0), "0e999".parse().unwrap());
+
+    // errors
+    assert_eq!("".parse::<Fraction>(), Err(ParseFractionError::Empty));
+    assert_eq!(" \t\n".parse::<Fraction>(), Err(ParseFractionError::Empty));
+    assert_eq!("1/".parse::<Fraction>(), Err(ParseFractionError::InvalidDigit));
+    assert_eq!("/2".parse::<Fraction>(), Err(ParseFractionError::InvalidDigit));
+    assert_eq!(".".parse::<Fraction>(), Err(ParseFractionError::InvalidDigit));
+    assert_eq!("1e".parse::<Fraction>(), Err(ParseFractionError::InvalidDigit));
+    assert_eq!("1.2.3".parse::<Fraction>(), Err(ParseFractionError::InvalidDigit));
+    assert_eq!("1 -1/2".parse::<Fraction>(), Err(ParseFractionError::InvalidDigit));
+    assert_eq!("1 1/-2".parse::<Fraction>(), Err(ParseFractionError::InvalidDigit));
+    assert_eq!("1 2".parse::<Fraction>(), Err(ParseFractionError::InvalidDigit));
+    assert_eq!("1/0".parse::<Fraction>(), Err(ParseFractionError::ZeroDenominator));
+    assert_eq!("1 1/0".parse::<Fraction>(), Err(ParseFractionError::ZeroDenominator));
+    assert_eq!("1/170141183460469231731687303715884105728".parse::<Fraction>(), Err(ParseFractionError::Overflow));
+    assert_eq!("1e39".parse::<Fraction>(), Err(ParseFractionError::Overflow));
+    assert_eq!("1e-40".parse::<Fraction>(), Err(ParseFractionError::Overflow));
+    assert_eq!("1e99999999999999999999999999999999999999999".parse::<Fraction>(), Err(ParseFractionError::Overflow));
+    assert_eq!("10e170141183460469231731687303715884105727".parse::<Fraction>(), Err(ParseFractionError::Overflow));
+    assert_eq!("1.5e-170141183460469231731687303715884105728".parse::<Fraction>(), Err(ParseFractionError::Overflow));
+    assert_eq!("1e-170141183460469231731687303715884105728".parse::<Fraction>(), Err(ParseFractionError::Overflow));
+    assert_eq!("0.0e170141183460469231731687303715884105727".parse::<Fraction>(), Ok(Fraction::new()));
 }
@@ -337,6 +337,10 @@ fn to_number(setup: Fixture) {
     assert_eq!(setup.zero.to_number::<i64>(), 0i64);
     assert_eq!(setup.positive.to_number::<i128>(), 18446744073709551617);
     assert_eq!(setup.negative.to_number::<i128>(), -18446744073709551617);
+
+    assert_eq!(f64::from(&setup.zero), 0.0);
+    assert_eq!(f64::from(setup.negative), -18446744073709551617.0);
+    assert_eq!(f64::from(Int::from("9".repeat(400).as_str())), f64::INFINITY);
 }
 
 #[rstest]
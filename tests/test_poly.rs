@@ -0,0 +1,179 @@
+use std::collections::HashSet;
+
+use pyinrs::{Complex, Fraction, Int, List, Poly};
+use rstest::{fixture, rstest};
+
+struct Fixture {
+    zero: Poly<Int>,
+    linear: Poly<Int>,
+    quadratic: Poly<Int>,
+}
+
+#[fixture]
+fn setup() -> Fixture {
+    Fixture {
+        zero: Poly::new(),
+        linear: int([-1, 1]),
+        quadratic: int([2, -3, 1]),
+    }
+}
+
+fn int<const N: usize>(coeffs: [i32; N]) -> Poly<Int> {
+    Poly::from(coeffs.map(Int::from))
+}
+
+fn frac<const N: usize>(coeffs: [(i128, i128); N]) -> Poly<Fraction> {
+    Poly::from(coeffs.map(Fraction::from))
+}
+
+#[rstest]
+fn basics(setup: Fixture) {
+    assert_eq!(setup.zero.degree(), -1);
+    assert_eq!(setup.linear.degree(), 1);
+    assert_eq!(int([1, 2, 0, 0]).degree(), 1);
+    assert_eq!(int([0, 0]), setup.zero);
+
+    assert!(setup.zero.is_zero());
+    assert!(!setup.quadratic.is_zero());
+    assert_eq!(setup.quadratic.coefficients(), [2, -3, 1].map(Int::from));
+    assert_eq!(Poly::from(List::from([1., 2.])), Poly::from([1., 2., 0.]));
+}
+
+#[rstest]
+fn compare(setup: Fixture) {
+    assert!(setup.linear == int([-1, 1]));
+    assert!(setup.linear != setup.quadratic);
+
+    let set = HashSet::from([setup.linear.clone(), int([-1, 1, 0]), setup.quadratic]);
+    assert_eq!(set.len(), 2);
+}
+
+#[rstest]
+fn arithmetic(setup: Fixture) {
+    assert_eq!(&setup.linear + &setup.quadratic, int([1, -2, 1]));
+    assert_eq!(&setup.quadratic - &setup.quadratic, setup.zero);
+    assert_eq!(&setup.linear * &int([1, 1]), int([-1, 0, 1]));
+    assert_eq!(&setup.linear * &setup.zero, setup.zero);
+    assert_eq!(-setup.linear, int([1, -1]));
+
+    let mut p = setup.quadratic.clone();
+    p *= int([0, 1]);
+    p -= int([0, 2]);
+    p += setup.zero;
+    assert_eq!(p, int([0, 0, -3, 1]));
+}
+
+#[rstest]
+fn calculus(setup: Fixture) {
+    assert_eq!(setup.quadratic.eval(&Int::from(5)), Int::from(12));
+    assert_eq!(setup.zero.eval(&Int::from(5)), Int::new());
+    assert_eq!(Poly::from([1., 0., 2.]).eval(&0.5), 1.5);
+
+    assert_eq!(setup.quadratic.derivative(), int([-3, 2]));
+    assert_eq!(int([7]).derivative(), setup.zero);
+
+    assert_eq!(frac([(1, 1), (1, 1), (3, 1)]).integral(), frac([(0, 1), (1, 1), (1, 2), (1, 1)]));
+    assert_eq!(frac([(1, 3), (2, 5)]).integral().derivative(), frac([(1, 3), (2, 5)]));
+    assert_eq!(Poly::<f64>::new().integral(), Poly::new());
+}
+
+#[rstest]
+#[case::no_panic(1)]
+#[should_panic(expected = "Error: Divide by zero.")]
+#[case::panic_with_message(2)]
+fn divmod(#[case] case: i32) {
+    let a = frac([(-4, 1), (0, 1), (-2, 1), (1, 1)]);
+    let b = frac([(-3, 1), (1, 1)]);
+
+    match case {
+        1 => {
+            let (q, r) = a.divmod(&b);
+            assert_eq!(q, frac([(3, 1), (1, 1), (1, 1)]));
+            assert_eq!(r, frac([(5, 1)]));
+            assert_eq!(&q * &b + &r, a);
+            assert_eq!(&a / &b, q);
+            assert_eq!(&a % &b, r);
+            assert_eq!(b.divmod(&a), (Poly::new(), b.clone()));
+
+            let (q, r) = frac([(1, 1), (0, 1), (1, 1)]).divmod(&frac([(0, 1), (2, 1)]));
+            assert_eq!((q, r), (frac([(0, 1), (1, 2)]), frac([(1, 1)])));
+
+            assert_eq!(frac([(2, 1), (4, 1)]).monic(), frac([(1, 2), (1, 1)]));
+        }
+        2 => {
+            let _ = a / Poly::new();
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[rstest]
+fn gcd() {
+    let a = frac([(2, 1), (-3, 1), (1, 1)]); // (x - 1)(x - 2)
+    let b = frac([(-3, 1), (2, 1), (1, 1)]); // (x - 1)(x + 3)
+    assert_eq!(Poly::gcd(&a, &b), frac([(-1, 1), (1, 1)]));
+    assert_eq!(Poly::gcd(&(&a * &frac([(2, 1)])), &a), a);
+    assert_eq!(Poly::gcd(&a, &frac([(1, 1), (1, 1)])), frac([(1, 1)]));
+    assert_eq!(Poly::gcd(&a, &Poly::new()), a);
+}
+
+#[rstest]
+fn roots(setup: Fixture) {
+    let close = |roots: List<Complex>, expected: &[(f64, f64)]| {
+        assert_eq!(roots.len() as usize, expected.len());
+        for (z, &e) in roots.iter().zip(expected) {
+            assert!(z.is_close(&Complex::from(e), 1e-9, 1e-9), "{z} != {e:?}");
+        }
+    };
+
+    close(setup.quadratic.roots(), &[(1., 0.), (2., 0.)]);
+    close(setup.linear.roots(), &[(1., 0.)]);
+    close(int([1, 0, 1]).roots(), &[(0., -1.), (0., 1.)]);
+    close(int([0, 0, 3]).roots(), &[(0., 0.), (0., 0.)]);
+    close(int([-8, 0, 0, 1]).roots(), &[(-1., -f64::sqrt(3.)), (-1., f64::sqrt(3.)), (2., 0.)]);
+    close(int([720, -1764, 1624, -735, 175, -21, 1]).roots(), &[1., 2., 3., 4., 5., 6.].map(|r| (r, 0.)));
+    close(frac([(-1, 4), (0, 1), (1, 1)]).roots(), &[(-0.5, 0.), (0.5, 0.)]);
+    close(
+        Poly::from([1., 2., 3., 4., 5.]).roots(),
+        &[
+            (-0.5378322749029899, -0.358284686345128),
+            (-0.5378322749029899, 0.358284686345128),
+            (0.13783227490298988, -0.6781543891053364),
+            (0.13783227490298988, 0.6781543891053364),
+        ],
+    );
+
+    assert_eq!(int([5]).roots(), List::new());
+    assert_eq!(setup.zero.roots(), List::new());
+}
+
+#[rstest]
+fn rational_roots(setup: Fixture) {
+    let fracs = |roots: &[(i128, i128)]| roots.iter().map(|&r| Fraction::from(r)).collect::<List<_>>();
+
+    // (2x - 1)(x + 2)^2(x^2 + 1)(x - 3)
+    let p = &(&int([-1, 2]) * &int([2, 1])) * &(&int([2, 1]) * &int([1, 0, 1])) * int([-3, 1]);
+    assert_eq!(p.rational_roots(), fracs(&[(-2, 1), (-2, 1), (1, 2), (3, 1)]));
+
+    assert_eq!(setup.quadratic.rational_roots(), fracs(&[(1, 1), (2, 1)]));
+    assert_eq!(int([0, 0, 0, 7]).rational_roots(), fracs(&[(0, 1), (0, 1), (0, 1)]));
+    assert_eq!(int([-2, 0, 1]).rational_roots(), List::new());
+    assert_eq!(frac([(-1, 9), (0, 1), (1, 1)]).rational_roots(), fracs(&[(-1, 3), (1, 3)]));
+    assert_eq!(Poly::from([-0.25, 1.]).rational_roots(), fracs(&[(1, 4)]));
+    assert_eq!(Poly::from([Int::from(-1), Int::from(3000000001i64)]).rational_roots(), fracs(&[(1, 3000000001)]));
+    assert_eq!(Poly::from([Int::from(-3000000001i64), Int::from(1)]).rational_roots(), fracs(&[(3000000001, 1)]));
+
+    assert_eq!(int([5]).rational_roots(), List::new());
+    assert_eq!(setup.zero.rational_roots(), List::new());
+}
+
+#[rstest]
+fn format(setup: Fixture) {
+    assert_eq!(setup.zero.to_string(), "0");
+    assert_eq!(setup.linear.to_string(), "x - 1");
+    assert_eq!(setup.quadratic.to_string(), "x^2 - 3*x + 2");
+    assert_eq!(int([0, -1]).to_string(), "-x");
+    assert_eq!(int([-4, 0, 0, -2]).to_string(), "-2*x^3 - 4");
+    assert_eq!(frac([(1, 2), (0, 1), (-3, 4)]).to_string(), "-3/4*x^2 + 1/2");
+    assert_eq!(Poly::from([1.5, 1.]).to_string(), "x + 1.5");
+}